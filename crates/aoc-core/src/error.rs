@@ -0,0 +1,333 @@
+use std::{fmt, ops::Range};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Error {
+    message: String,
+    location: Option<Box<Location>>,
+    // Shown next to the underlined text, e.g. `while parsing the bid`.
+    label: Option<String>,
+    // Further errors in the same input, from parsers that carry on after the
+    // first one.
+    others: Vec<Error>,
+    // How many more errors were found but not kept, for inputs with too many
+    // to hold on to.
+    omitted: usize,
+}
+
+// Where in the input an error was found. Lines and columns start at 1, and
+// columns count characters rather than bytes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Location {
+    pub line: usize,
+    pub column: usize,
+    // The whole line the error is on, and the offending text within it.
+    pub source_line: String,
+    pub text: String,
+}
+
+impl Error {
+    pub fn new(message: impl Into<String>) -> Self {
+        Error {
+            message: message.into(),
+            location: None,
+            label: None,
+            others: Vec::new(),
+            omitted: 0,
+        }
+    }
+
+    // An error about the text at `span`, given as byte offsets into `input`.
+    // A span past the end of the input points at the end of the last line.
+    pub fn at(input: &str, span: Range<usize>, message: impl Into<String>) -> Self {
+        let start = span.start.min(input.len());
+        let line_start = input[..start].rfind('\n').map_or(0, |i| i + 1);
+        let line_end = input[start..].find('\n').map_or(input.len(), |i| start + i);
+        let index = input[..start].matches('\n').count();
+
+        Error::in_line(
+            index,
+            &input[line_start..line_end],
+            start - line_start..span.end.clamp(start, line_end) - line_start,
+            message,
+        )
+    }
+
+    // An error about the text at `span`, given as byte offsets into `line`,
+    // which is the line at `index` (counting from 0) in the input.
+    pub fn in_line(
+        index: usize,
+        line: &str,
+        span: Range<usize>,
+        message: impl Into<String>,
+    ) -> Self {
+        let line = line.strip_suffix('\r').unwrap_or(line);
+        let start = span.start.min(line.len());
+        let end = span.end.clamp(start, line.len());
+
+        Error {
+            message: message.into(),
+            location: Some(Box::new(Location {
+                line: index + 1,
+                column: line[..start].chars().count() + 1,
+                source_line: line.to_string(),
+                text: line[start..end].to_string(),
+            })),
+            label: None,
+            others: Vec::new(),
+            omitted: 0,
+        }
+    }
+
+    pub fn with_label(self, label: impl Into<String>) -> Self {
+        Error {
+            label: Some(label.into()),
+            ..self
+        }
+    }
+
+    // Combine several errors found in the same input into one, ordered by
+    // where they are in the input. Returns `None` if there are no errors.
+    pub fn combine(errors: impl IntoIterator<Item = Error>) -> Option<Self> {
+        let mut errors = errors.into_iter().collect::<Vec<_>>();
+        errors.sort_by_key(|error| {
+            error
+                .location
+                .as_ref()
+                .map(|location| (location.line, location.column))
+        });
+
+        let omitted = errors.iter().map(|error| error.omitted).sum();
+        let mut errors = errors.into_iter();
+        let first = errors.next()?;
+        Some(Error {
+            others: errors.collect(),
+            omitted,
+            ..first
+        })
+    }
+
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    pub fn location(&self) -> Option<&Location> {
+        self.location.as_deref()
+    }
+
+    pub fn label(&self) -> Option<&str> {
+        self.label.as_deref()
+    }
+
+    pub fn others(&self) -> &[Error] {
+        &self.others
+    }
+
+    // Note that `omitted` more errors were found besides these.
+    pub fn with_omitted(self, omitted: usize) -> Self {
+        Error {
+            omitted: self.omitted + omitted,
+            ..self
+        }
+    }
+
+    pub fn omitted(&self) -> usize {
+        self.omitted
+    }
+
+    fn fmt_one(&self, f: &mut fmt::Formatter<'_>, snippet: bool) -> fmt::Result {
+        let Some(location) = &self.location else {
+            return f.write_str(&self.message);
+        };
+
+        write!(
+            f,
+            "line {}, column {}: {}",
+            location.line, location.column, self.message
+        )?;
+
+        match &self.label {
+            Some(label) if !snippet => write!(f, " ({label})"),
+            label if snippet => {
+                let gutter = location.line.to_string().len();
+                let underline = location.text.chars().count().max(1);
+                write!(
+                    f,
+                    "\n{:gutter$} |\n{} | {}\n{:gutter$} | {:indent$}{}",
+                    "",
+                    location.line,
+                    location.source_line,
+                    "",
+                    "",
+                    "^".repeat(underline),
+                    indent = location.column - 1,
+                )?;
+                match label {
+                    Some(label) => write!(f, " {label}"),
+                    None => Ok(()),
+                }
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+// Formats as `line 3, column 7: message`, mentioning how many other errors
+// there are. The alternate form (`{:#}`) shows every error along with the
+// offending line and the text underlined, e.g.:
+//
+// line 3, column 7: unexpected character `%`
+//   |
+// 3 | 617*..%...
+//   |       ^
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if f.alternate() {
+            self.fmt_one(f, true)?;
+            for other in &self.others {
+                f.write_str("\n\n")?;
+                other.fmt_one(f, true)?;
+            }
+
+            return match self.omitted {
+                0 => Ok(()),
+                1 => f.write_str("\n\n(and 1 more error not shown)"),
+                n => write!(f, "\n\n(and {n} more errors not shown)"),
+            };
+        }
+
+        self.fmt_one(f, false)?;
+        match self.others.len() + self.omitted {
+            0 => Ok(()),
+            1 => f.write_str(" (and 1 more error)"),
+            n => write!(f, " (and {n} more errors)"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+#[cfg(feature = "chumsky")]
+impl Error {
+    // Convert the errors from a chumsky parser run on `input`.
+    pub fn from_chumsky(input: &str, errors: Vec<chumsky::error::Simple<char>>) -> Self {
+        use chumsky::error::SimpleReason;
+
+        let describe = |c: Option<char>| match c {
+            None => "end of input".to_string(),
+            Some('\r' | '\n') => "end of line".to_string(),
+            Some(c) if c.is_ascii_digit() => "a digit".to_string(),
+            Some(c) => format!("`{c}`"),
+        };
+        // Chumsky spans count characters, not bytes.
+        let offset = |index: usize| {
+            input
+                .char_indices()
+                .nth(index)
+                .map_or(input.len(), |(offset, _)| offset)
+        };
+
+        let errors = errors.iter().map(|error| {
+            let message = match error.reason() {
+                SimpleReason::Custom(message) => message.clone(),
+                SimpleReason::Unclosed { delimiter, .. } => format!("unclosed `{delimiter}`"),
+                SimpleReason::Unexpected => {
+                    // Some parsers, like keywords, report the text they didn't
+                    // accept rather than a single character.
+                    let span = error.span();
+                    let text = &input[offset(span.start)..offset(span.end)];
+                    let found = match error.found() {
+                        None if !text.is_empty() => format!("`{text}`"),
+                        found => describe(found.copied()),
+                    };
+                    let mut expected = error
+                        .expected()
+                        .map(|&expected| describe(expected))
+                        .collect::<Vec<_>>();
+                    expected.sort();
+                    expected.dedup();
+
+                    match expected.as_slice() {
+                        [] => format!("unexpected {found}"),
+                        [expected] => format!("unexpected {found}, expected {expected}"),
+                        _ => format!(
+                            "unexpected {found}, expected one of {}",
+                            expected.join(", ")
+                        ),
+                    }
+                }
+            };
+
+            let span = error.span();
+            let located = Error::at(input, offset(span.start)..offset(span.end), message);
+            match error.label() {
+                Some(label) => located.with_label(format!("while parsing the {label}")),
+                None => located,
+            }
+        });
+
+        Error::combine(errors).unwrap_or_else(|| Error::new("invalid input"))
+    }
+}
+
+#[test]
+fn error_location() {
+    let input = "467..114..\n...*......\n..35..633.\n";
+
+    let error = Error::at(input, 14..15, "unexpected `*`");
+    assert_eq!(
+        error.location(),
+        Some(&Location {
+            line: 2,
+            column: 4,
+            source_line: "...*......".to_string(),
+            text: "*".to_string(),
+        })
+    );
+    assert_eq!(error.to_string(), "line 2, column 4: unexpected `*`");
+    assert_eq!(
+        format!("{error:#}"),
+        "line 2, column 4: unexpected `*`\n  |\n2 | ...*......\n  |    ^"
+    );
+
+    let error = Error::at(input, 33..40, "unexpected end of input");
+    assert_eq!(error.location().map(|l| (l.line, l.column)), Some((4, 1)));
+}
+
+#[test]
+fn error_combine() {
+    let input = "Card 1: 41 48 | 83 86\nCard 2: 13 x2 | 61 30\nCard 3 1 21 | 69 82\n";
+
+    let error = Error::combine([
+        Error::at(input, 51..52, "unexpected `1`, expected `:`")
+            .with_label("while parsing the card"),
+        Error::at(input, 33..34, "unexpected `x`"),
+    ])
+    .unwrap();
+    assert_eq!(error.others().len(), 1);
+    assert_eq!(
+        error.to_string(),
+        "line 2, column 12: unexpected `x` (and 1 more error)"
+    );
+    assert_eq!(
+        format!("{error:#}"),
+        "line 2, column 12: unexpected `x`
+  |
+2 | Card 2: 13 x2 | 61 30
+  |            ^
+
+line 3, column 8: unexpected `1`, expected `:`
+  |
+3 | Card 3 1 21 | 69 82
+  |        ^ while parsing the card"
+    );
+
+    let error = error.with_omitted(3);
+    assert_eq!(
+        error.to_string(),
+        "line 2, column 12: unexpected `x` (and 4 more errors)"
+    );
+    assert!(format!("{error:#}").ends_with("\n\n(and 3 more errors not shown)"));
+    assert_eq!(Error::combine([error]).unwrap().omitted(), 3);
+
+    assert_eq!(Error::combine([]), None);
+}
@@ -0,0 +1,177 @@
+use std::{
+    env, fmt,
+    io::{self, Read},
+    path::{Path, PathBuf},
+    process,
+};
+
+// Environment variable pointing at a directory containing `day1.txt`,
+// `day2.txt`, etc.
+pub const INPUT_DIR_VAR: &str = "AOC_INPUT_DIR";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputSource {
+    Stdin,
+    File(PathBuf),
+    // A directory containing a `dayN.txt` file per day.
+    Dir(PathBuf),
+    // The directory from `AOC_INPUT_DIR` if set, otherwise the `input.txt`
+    // next to the day's source code.
+    Default,
+}
+
+#[derive(Debug)]
+pub enum InputError {
+    NotFound { day: u8, path: PathBuf },
+    Io { path: PathBuf, source: io::Error },
+    Stdin(io::Error),
+}
+
+impl fmt::Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InputError::NotFound { day, path } => write!(
+                f,
+                "no input for day {day}: `{}` does not exist (pass an input path, `-` to read \
+                 from stdin, or set {INPUT_DIR_VAR} to a directory containing day{day}.txt)",
+                path.display()
+            ),
+            InputError::Io { path, source } => {
+                write!(f, "failed to read `{}`: {source}", path.display())
+            }
+            InputError::Stdin(source) => write!(f, "failed to read from stdin: {source}"),
+        }
+    }
+}
+
+impl std::error::Error for InputError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            InputError::NotFound { .. } => None,
+            InputError::Io { source, .. } | InputError::Stdin(source) => Some(source),
+        }
+    }
+}
+
+impl InputSource {
+    // Interpret a command line argument: `-` means stdin, anything else is a
+    // path to a file.
+    pub fn from_arg(arg: &str) -> Self {
+        if arg == "-" {
+            InputSource::Stdin
+        } else {
+            InputSource::File(arg.into())
+        }
+    }
+
+    // The file the input for `day` is read from, or `None` for stdin.
+    pub fn path(&self, day: u8) -> Option<PathBuf> {
+        match self {
+            InputSource::Stdin => None,
+            InputSource::File(path) => Some(path.clone()),
+            InputSource::Dir(dir) => Some(dir.join(format!("day{day}.txt"))),
+            InputSource::Default => match env::var_os(INPUT_DIR_VAR) {
+                Some(dir) => InputSource::Dir(dir.into()).path(day),
+                None => Some(default_path(day)),
+            },
+        }
+    }
+
+    // The expected answers are kept next to the input: `answers.toml` for an
+    // `input.txt`, or e.g. `day5.answers.toml` for `day5.txt`.
+    pub fn answers_path(&self, day: u8) -> Option<PathBuf> {
+        let path = self.path(day)?;
+        if path.file_name() == Some("input.txt".as_ref()) {
+            Some(path.with_file_name("answers.toml"))
+        } else {
+            Some(path.with_extension("answers.toml"))
+        }
+    }
+
+    pub fn read(&self, day: u8) -> Result<String, InputError> {
+        match self.path(day) {
+            Some(path) => read_file(day, &path),
+            None => {
+                let mut input = String::new();
+                io::stdin()
+                    .read_to_string(&mut input)
+                    .map_err(InputError::Stdin)?;
+
+                Ok(input)
+            }
+        }
+    }
+}
+
+fn default_path(day: u8) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("..")
+        .join(format!("day{day}"))
+        .join("src")
+        .join("input.txt")
+}
+
+fn read_file(day: u8, path: &Path) -> Result<String, InputError> {
+    std::fs::read_to_string(path).map_err(|source| match source.kind() {
+        io::ErrorKind::NotFound => InputError::NotFound {
+            day,
+            path: path.to_path_buf(),
+        },
+        _ => InputError::Io {
+            path: path.to_path_buf(),
+            source,
+        },
+    })
+}
+
+// Load the input for a day's standalone binary, taking an optional path (or
+// `-`) as the first command line argument. Exits the process if the input
+// can't be read.
+pub fn from_args(day: u8) -> String {
+    let source = env::args()
+        .nth(1)
+        .map_or(InputSource::Default, |arg| InputSource::from_arg(&arg));
+
+    read_or_exit(&source, day)
+}
+
+// Load the input for a day's standalone binary from `source`, exiting the
+// process if it can't be read.
+pub fn read_or_exit(source: &InputSource, day: u8) -> String {
+    source.read(day).unwrap_or_else(|e| {
+        eprintln!("error: {e}");
+        process::exit(1);
+    })
+}
+
+#[test]
+fn input_source_from_arg() {
+    assert_eq!(InputSource::from_arg("-"), InputSource::Stdin);
+    assert_eq!(
+        InputSource::from_arg("inputs/day5.txt"),
+        InputSource::File("inputs/day5.txt".into())
+    );
+}
+
+#[test]
+fn input_answers_path() {
+    assert_eq!(InputSource::Stdin.answers_path(5), None);
+    assert_eq!(
+        InputSource::File("day5/src/input.txt".into()).answers_path(5),
+        Some("day5/src/answers.toml".into())
+    );
+    assert_eq!(
+        InputSource::Dir("inputs".into()).answers_path(5),
+        Some("inputs/day5.answers.toml".into())
+    );
+}
+
+#[test]
+fn input_missing_file() {
+    let source = InputSource::Dir(env::temp_dir().join("aoc-inputs-that-do-not-exist"));
+
+    assert!(matches!(
+        source.read(5),
+        Err(InputError::NotFound { day: 5, path }) if path.ends_with("day5.txt")
+    ));
+}
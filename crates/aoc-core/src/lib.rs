@@ -0,0 +1,7 @@
+mod error;
+pub mod input;
+mod solution;
+pub mod text;
+
+pub use error::{Error, Location};
+pub use solution::{parse_or_exit, Solution};
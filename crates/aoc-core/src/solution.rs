@@ -0,0 +1,35 @@
+use std::{fmt::Display, process};
+
+use crate::Error;
+
+pub trait Solution {
+    const DAY: u8;
+
+    type Input;
+    type Answer1: Display;
+    type Answer2: Display;
+
+    fn parse(input: &str) -> Result<Self::Input, Error>;
+    fn part1(input: &Self::Input) -> Self::Answer1;
+    fn part2(input: &Self::Input) -> Self::Answer2;
+
+    // For a part that has no answer for some input that parses fine, like
+    // input only the other part makes sense of. The runner calls these, so
+    // that's reported as an error rather than a panic.
+    fn try_part1(input: &Self::Input) -> Result<Self::Answer1, Error> {
+        Ok(Self::part1(input))
+    }
+
+    fn try_part2(input: &Self::Input) -> Result<Self::Answer2, Error> {
+        Ok(Self::part2(input))
+    }
+}
+
+// Parse the input for a day's standalone binary, exiting the process if it's
+// invalid.
+pub fn parse_or_exit<S: Solution>(input: &str) -> S::Input {
+    S::parse(input).unwrap_or_else(|e| {
+        eprintln!("error: failed to parse input for day {}: {e:#}", S::DAY);
+        process::exit(1);
+    })
+}
@@ -0,0 +1,86 @@
+use std::{fmt::Display, ops::Range, str::FromStr};
+
+#[cfg(feature = "chumsky")]
+use chumsky::error::Simple;
+
+use crate::Error;
+
+// The whitespace separated words in `s`, along with their byte offsets in it.
+pub fn words(s: &str) -> impl Iterator<Item = (usize, &str)> {
+    s.split_ascii_whitespace()
+        .map(move |word| (word.as_ptr() as usize - s.as_ptr() as usize, word))
+}
+
+// Parse the number at `span` in the line at `index` (counting from 0) in the
+// input, pointing at it if it's not a valid number.
+pub fn number<T>(index: usize, line: &str, span: Range<usize>) -> Result<T, Error>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    let text = &line[span.clone()];
+    text.parse()
+        .map_err(|e| Error::in_line(index, line, span, format!("invalid number `{text}`: {e}")))
+}
+
+// Parse `item` on each line up to an empty line or the end of the input. A
+// line that doesn't parse is skipped after recording the error, so the errors
+// on all lines get reported rather than only the first one.
+#[cfg(feature = "chumsky")]
+pub fn lines<O, P>(item: P) -> impl chumsky::Parser<char, Vec<O>, Error = Simple<char>> + Clone
+where
+    P: chumsky::Parser<char, O, Error = Simple<char>> + Clone,
+{
+    use chumsky::prelude::*;
+
+    let end_of_line = text::newline().or(end()).rewind();
+
+    // Check there is a line first, so the list ends cleanly instead of
+    // recovering from an empty one.
+    none_of("\r\n")
+        .rewind()
+        .ignore_then(
+            item.then_ignore(end_of_line)
+                .map(Some)
+                .recover_with(skip_until(['\n'], |_| None)),
+        )
+        .separated_by(text::newline())
+        .at_least(1)
+        .map(|items| items.into_iter().flatten().collect())
+}
+
+#[test]
+fn text_words() {
+    let line = "Time:      7  15   30";
+
+    assert_eq!(
+        words(line).collect::<Vec<_>>(),
+        [(0, "Time:"), (11, "7"), (14, "15"), (19, "30")]
+    );
+    assert_eq!(number::<u32>(0, line, 14..16), Ok(15));
+    assert_eq!(
+        number::<u8>(0, line, 0..5).unwrap_err().to_string(),
+        "line 1, column 1: invalid number `Time:`: invalid digit found in string"
+    );
+}
+
+#[cfg(feature = "chumsky")]
+#[test]
+fn text_lines_recovery() {
+    use chumsky::prelude::*;
+
+    let parser = lines(text::int::<_, Simple<char>>(10)).then_ignore(end());
+
+    assert_eq!(parser.parse("1\n22\n333").unwrap(), ["1", "22", "333"]);
+
+    let errors = parser.parse("1\nx2\n3\n4y").unwrap_err();
+    let error = Error::from_chumsky("1\nx2\n3\n4y", errors);
+    assert_eq!(
+        error.to_string(),
+        "line 2, column 1: unexpected `x`, expected a digit (and 1 more error)"
+    );
+    assert_eq!(
+        error.others()[0].to_string(),
+        "line 4, column 2: unexpected `y`, expected one of end of input, end of line"
+    );
+}
@@ -0,0 +1,354 @@
+use std::ops::{Index, IndexMut};
+
+use aoc_core::Error;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Position {
+    pub x: usize,
+    pub y: usize,
+}
+
+impl Position {
+    pub const fn new(x: usize, y: usize) -> Self {
+        Position { x, y }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction {
+    North,
+    East,
+    South,
+    West,
+}
+
+impl Direction {
+    pub const ALL: [Direction; 4] = [
+        Direction::North,
+        Direction::East,
+        Direction::South,
+        Direction::West,
+    ];
+
+    pub fn opposite(self) -> Direction {
+        match self {
+            Direction::North => Direction::South,
+            Direction::East => Direction::West,
+            Direction::South => Direction::North,
+            Direction::West => Direction::East,
+        }
+    }
+
+    pub fn turn_left(self) -> Direction {
+        match self {
+            Direction::North => Direction::West,
+            Direction::East => Direction::North,
+            Direction::South => Direction::East,
+            Direction::West => Direction::South,
+        }
+    }
+
+    pub fn turn_right(self) -> Direction {
+        self.turn_left().opposite()
+    }
+}
+
+// A rectangular grid of cells, stored row by row.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    width: usize,
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, cells: Vec<T>) -> Self {
+        assert!(
+            width == 0 && cells.is_empty() || width > 0 && cells.len().is_multiple_of(width),
+            "{} cells don't fit in rows of width {width}",
+            cells.len()
+        );
+
+        Grid { cells, width }
+    }
+
+    // Parse a grid with one row per line, mapping each character to a cell or
+    // to an explanation of why it's not a valid one.
+    pub fn parse(
+        input: &str,
+        mut cell: impl FnMut(char) -> Result<T, String>,
+    ) -> Result<Self, Error> {
+        let mut width = None;
+        let mut cells = Vec::with_capacity(input.len());
+
+        for (y, line) in input.lines().enumerate() {
+            let line_width = line.chars().count();
+            match width {
+                None => width = Some(line_width),
+                Some(width) if line_width > width => {
+                    let start = line.char_indices().nth(width).map_or(0, |(i, _)| i);
+                    return Err(Error::in_line(
+                        y,
+                        line,
+                        start..line.len(),
+                        format!("row is {line_width} characters wide, expected {width}"),
+                    ));
+                }
+                Some(width) if line_width < width => {
+                    return Err(Error::in_line(
+                        y,
+                        line,
+                        line.len()..line.len(),
+                        format!("row is only {line_width} characters wide, expected {width}"),
+                    ));
+                }
+                Some(_) => {}
+            }
+
+            for (i, c) in line.char_indices() {
+                let cell = cell(c).map_err(|e| Error::in_line(y, line, i..i + c.len_utf8(), e))?;
+                cells.push(cell);
+            }
+        }
+
+        Ok(Grid::new(width.unwrap_or(0), cells))
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.cells.len().checked_div(self.width).unwrap_or(0)
+    }
+
+    pub fn cells(&self) -> &[T] {
+        &self.cells
+    }
+
+    pub fn contains(&self, position: Position) -> bool {
+        position.x < self.width && position.y < self.height()
+    }
+
+    pub fn index_of(&self, position: Position) -> usize {
+        position.y * self.width + position.x
+    }
+
+    pub fn position_of(&self, index: usize) -> Position {
+        Position {
+            x: index % self.width,
+            y: index / self.width,
+        }
+    }
+
+    pub fn get(&self, position: Position) -> Option<&T> {
+        self.contains(position)
+            .then(|| &self.cells[self.index_of(position)])
+    }
+
+    pub fn get_mut(&mut self, position: Position) -> Option<&mut T> {
+        if self.contains(position) {
+            let index = self.index_of(position);
+            Some(&mut self.cells[index])
+        } else {
+            None
+        }
+    }
+
+    pub fn swap(&mut self, a: Position, b: Position) {
+        let a = self.index_of(a);
+        let b = self.index_of(b);
+        self.cells.swap(a, b);
+    }
+
+    // All positions in the grid, row by row.
+    pub fn positions(&self) -> impl DoubleEndedIterator<Item = Position> {
+        let width = self.width;
+        (0..self.cells.len()).map(move |i| Position {
+            x: i % width,
+            y: i / width,
+        })
+    }
+
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = (Position, &T)> {
+        self.cells
+            .iter()
+            .enumerate()
+            .map(|(i, cell)| (self.position_of(i), cell))
+    }
+
+    // The position one step away in the given direction, if it's still inside
+    // the grid.
+    pub fn step(&self, position: Position, direction: Direction) -> Option<Position> {
+        let next = match direction {
+            Direction::North => Position {
+                x: position.x,
+                y: position.y.checked_sub(1)?,
+            },
+            Direction::East => Position {
+                x: position.x + 1,
+                y: position.y,
+            },
+            Direction::South => Position {
+                x: position.x,
+                y: position.y + 1,
+            },
+            Direction::West => Position {
+                x: position.x.checked_sub(1)?,
+                y: position.y,
+            },
+        };
+
+        self.contains(next).then_some(next)
+    }
+
+    // The horizontally and vertically adjacent positions.
+    pub fn neighbours4(&self, position: Position) -> impl Iterator<Item = Position> + '_ {
+        Direction::ALL
+            .into_iter()
+            .filter_map(move |direction| self.step(position, direction))
+    }
+
+    // The horizontally, vertically and diagonally adjacent positions.
+    pub fn neighbours8(&self, position: Position) -> impl Iterator<Item = Position> + '_ {
+        (-1isize..=1)
+            .flat_map(|dy| (-1isize..=1).map(move |dx| (dx, dy)))
+            .filter(|&offset| offset != (0, 0))
+            .filter_map(move |(dx, dy)| {
+                let next = Position {
+                    x: position.x.checked_add_signed(dx)?,
+                    y: position.y.checked_add_signed(dy)?,
+                };
+
+                self.contains(next).then_some(next)
+            })
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl DoubleEndedIterator<Item = &[T]> {
+        // `chunks` panics on a chunk size of zero, which an empty grid has.
+        self.cells.chunks(self.width.max(1))
+    }
+
+    pub fn column(&self, x: usize) -> impl DoubleEndedIterator<Item = &T> {
+        assert!(x < self.width, "column {x} is out of bounds");
+        self.cells.iter().skip(x).step_by(self.width)
+    }
+
+    pub fn columns(&self) -> impl DoubleEndedIterator<Item = impl DoubleEndedIterator<Item = &T>> {
+        (0..self.width).map(|x| self.column(x))
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    // Mirror the grid along its diagonal, so rows become columns.
+    pub fn transpose(&self) -> Self {
+        Grid {
+            cells: self.columns().flatten().cloned().collect(),
+            width: self.height(),
+        }
+    }
+
+    pub fn rotate_clockwise(&self) -> Self {
+        Grid {
+            cells: self
+                .columns()
+                .flat_map(|column| column.rev())
+                .cloned()
+                .collect(),
+            width: self.height(),
+        }
+    }
+
+    pub fn rotate_counterclockwise(&self) -> Self {
+        Grid {
+            cells: self.columns().rev().flatten().cloned().collect(),
+            width: self.height(),
+        }
+    }
+}
+
+impl<T> Index<Position> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, position: Position) -> &T {
+        assert!(self.contains(position), "{position:?} is out of bounds");
+        &self.cells[self.index_of(position)]
+    }
+}
+
+impl<T> IndexMut<Position> for Grid<T> {
+    fn index_mut(&mut self, position: Position) -> &mut T {
+        assert!(self.contains(position), "{position:?} is out of bounds");
+        let index = self.index_of(position);
+        &mut self.cells[index]
+    }
+}
+
+impl<T> Index<usize> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, index: usize) -> &T {
+        &self.cells[index]
+    }
+}
+
+impl<T> IndexMut<usize> for Grid<T> {
+    fn index_mut(&mut self, index: usize) -> &mut T {
+        &mut self.cells[index]
+    }
+}
+
+#[cfg(test)]
+const TEST_INPUT: &str = "abc
+def";
+
+#[test]
+fn grid_indexing() {
+    let grid = Grid::parse(TEST_INPUT, Ok).unwrap();
+
+    assert_eq!(grid.width(), 3);
+    assert_eq!(grid.height(), 2);
+    assert_eq!(grid[Position::new(1, 1)], 'e');
+    assert_eq!(grid[5], 'f');
+    assert_eq!(grid.position_of(5), Position::new(2, 1));
+    assert_eq!(grid.get(Position::new(3, 0)), None);
+    assert_eq!(grid.row(1), &['d', 'e', 'f']);
+    assert_eq!(grid.column(2).collect::<String>(), "cf");
+
+    assert_eq!(grid.step(Position::new(0, 0), Direction::North), None);
+    assert_eq!(
+        grid.step(Position::new(0, 0), Direction::South),
+        Some(Position::new(0, 1))
+    );
+    assert_eq!(grid.neighbours4(Position::new(0, 0)).count(), 2);
+    assert_eq!(grid.neighbours8(Position::new(1, 0)).count(), 5);
+
+    let error = Grid::parse("abc\nde", Ok).unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "line 2, column 3: row is only 2 characters wide, expected 3"
+    );
+}
+
+#[test]
+fn grid_transformations() {
+    let grid = Grid::parse(TEST_INPUT, Ok).unwrap();
+
+    let transposed = grid.transpose();
+    assert_eq!(transposed.width(), 2);
+    assert_eq!(transposed.cells().iter().collect::<String>(), "adbecf");
+
+    let rotated = grid.rotate_clockwise();
+    assert_eq!(rotated.cells().iter().collect::<String>(), "daebfc");
+    assert_eq!(rotated.rotate_counterclockwise(), grid);
+    assert_eq!(
+        grid.rotate_counterclockwise()
+            .cells()
+            .iter()
+            .collect::<String>(),
+        "cfbead"
+    );
+}
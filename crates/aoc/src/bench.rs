@@ -0,0 +1,238 @@
+use std::{
+    collections::HashMap,
+    fmt, fs,
+    hint::black_box,
+    io,
+    path::Path,
+    time::{Duration, Instant},
+};
+
+use aoc_core::Solution;
+
+use crate::days::{catch, Part};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Stage {
+    Parse,
+    Solve(Part),
+}
+
+impl fmt::Display for Stage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Stage::Parse => f.pad("parse"),
+            Stage::Solve(part) => f.pad(&format!("part {part}")),
+        }
+    }
+}
+
+impl Stage {
+    fn key(&self) -> &'static str {
+        match self {
+            Stage::Parse => "parse",
+            Stage::Solve(part) => part.key(),
+        }
+    }
+
+    fn from_key(key: &str) -> Option<Self> {
+        if key == "parse" {
+            return Some(Stage::Parse);
+        }
+
+        Part::ALL
+            .into_iter()
+            .find(|part| part.key() == key)
+            .map(Stage::Solve)
+    }
+}
+
+#[derive(Debug)]
+pub struct Measurement {
+    pub day: u8,
+    pub stage: Stage,
+    pub input_len: usize,
+    // Sorted from fastest to slowest.
+    pub samples: Vec<Duration>,
+}
+
+impl Measurement {
+    pub fn min(&self) -> Duration {
+        self.samples[0]
+    }
+
+    pub fn median(&self) -> Duration {
+        self.samples[self.samples.len() / 2]
+    }
+
+    pub fn max(&self) -> Duration {
+        self.samples[self.samples.len() - 1]
+    }
+
+    // Megabytes of input processed per second, based on the median run.
+    pub fn throughput(&self) -> f64 {
+        self.input_len as f64 / self.median().as_secs_f64() / 1_000_000.0
+    }
+}
+
+fn measure<T>(iterations: usize, mut stage: impl FnMut() -> T) -> Vec<Duration> {
+    let mut samples = (0..iterations)
+        .map(|_| {
+            let start = Instant::now();
+            black_box(stage());
+            start.elapsed()
+        })
+        .collect::<Vec<_>>();
+    samples.sort();
+
+    samples
+}
+
+pub fn bench<S: Solution>(
+    input: &str,
+    parts: &[Part],
+    iterations: usize,
+) -> Result<Vec<Measurement>, String> {
+    let measurement = |stage, samples| Measurement {
+        day: S::DAY,
+        stage,
+        input_len: input.len(),
+        samples,
+    };
+
+    // Parse once up front so we have something to feed the parts, and so
+    // invalid input is reported instead of benchmarked.
+    let parsed = catch(|| S::parse(input))?.map_err(|e| format!("failed to parse input: {e:#}"))?;
+
+    let mut measurements = vec![measurement(
+        Stage::Parse,
+        measure(iterations, || S::parse(black_box(input))),
+    )];
+    for &part in parts {
+        let solve = || match part {
+            Part::One => S::try_part1(black_box(&parsed)).map(|answer| answer.to_string()),
+            Part::Two => S::try_part2(black_box(&parsed)).map(|answer| answer.to_string()),
+        };
+        // Solve once first, so a part without an answer is reported instead
+        // of benchmarked.
+        catch(solve)?.map_err(|e| format!("part {part} has no answer: {e:#}"))?;
+        let samples = catch(|| measure(iterations, solve))?;
+        measurements.push(measurement(Stage::Solve(part), samples));
+    }
+
+    Ok(measurements)
+}
+
+// Median timings from a previous run, keyed by day and stage.
+#[derive(Debug, Default)]
+pub struct Baseline(HashMap<(u8, Stage), Duration>);
+
+impl Baseline {
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let contents = fs::read_to_string(path)
+            .map_err(|e| format!("failed to read baseline `{}`: {e}", path.display()))?;
+
+        let mut medians = HashMap::new();
+        for (i, line) in contents.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let invalid = || format!("{}:{}: invalid baseline entry", path.display(), i + 1);
+            let mut fields = line.split_ascii_whitespace();
+            let day = fields.next().and_then(|day| day.parse().ok());
+            let stage = fields.next().and_then(Stage::from_key);
+            let nanos = fields.next().and_then(|nanos| nanos.parse().ok());
+            let (Some(day), Some(stage), Some(nanos), None) = (day, stage, nanos, fields.next())
+            else {
+                return Err(invalid());
+            };
+
+            medians.insert((day, stage), Duration::from_nanos(nanos));
+        }
+
+        Ok(Baseline(medians))
+    }
+
+    pub fn save(path: &Path, measurements: &[Measurement]) -> io::Result<()> {
+        let mut contents = String::from("# day stage median_ns\n");
+        for measurement in measurements {
+            contents += &format!(
+                "{} {} {}\n",
+                measurement.day,
+                measurement.stage.key(),
+                measurement.median().as_nanos()
+            );
+        }
+
+        fs::write(path, contents)
+    }
+
+    // The relative change of the median compared to the baseline, e.g. `0.1`
+    // if it got 10% slower.
+    pub fn change(&self, measurement: &Measurement) -> Option<f64> {
+        let baseline = self.0.get(&(measurement.day, measurement.stage))?;
+
+        Some(measurement.median().as_secs_f64() / baseline.as_secs_f64() - 1.0)
+    }
+}
+
+// Print the measurements, comparing them to the baseline if there is one.
+// Returns whether any stage got slower than the threshold allows.
+pub fn print_report(
+    measurements: &[Measurement],
+    baseline: Option<&Baseline>,
+    threshold: f64,
+) -> bool {
+    let mut regressed = false;
+
+    println!(
+        "{:>3}  {:<6}  {:>10}  {:>10}  {:>10}  {:>12}  {:>8}",
+        "day", "stage", "min", "median", "max", "throughput", "change"
+    );
+    for measurement in measurements {
+        let change = baseline.and_then(|baseline| baseline.change(measurement));
+        let change = match change {
+            Some(change) if change > threshold => {
+                regressed = true;
+                format!("{:+.1}%  slower", change * 100.0)
+            }
+            Some(change) => format!("{:+.1}%", change * 100.0),
+            None => String::new(),
+        };
+
+        println!(
+            "{:>3}  {:<6}  {:>10}  {:>10}  {:>10}  {:>7.1} MB/s  {:>8}",
+            measurement.day,
+            measurement.stage,
+            format!("{:.2?}", measurement.min()),
+            format!("{:.2?}", measurement.median()),
+            format!("{:.2?}", measurement.max()),
+            measurement.throughput(),
+            change,
+        );
+    }
+
+    regressed
+}
+
+#[test]
+fn bench_baseline_round_trip() {
+    let measurement = Measurement {
+        day: 5,
+        stage: Stage::Solve(Part::Two),
+        input_len: 1000,
+        samples: vec![Duration::from_millis(2), Duration::from_millis(4)],
+    };
+    let path = std::env::temp_dir().join("aoc-bench-baseline-round-trip.txt");
+    Baseline::save(&path, std::slice::from_ref(&measurement)).unwrap();
+
+    let baseline = Baseline::load(&path).unwrap();
+    assert_eq!(baseline.change(&measurement), Some(0.0));
+
+    let slower = Measurement {
+        samples: vec![Duration::from_millis(5), Duration::from_millis(6)],
+        ..measurement
+    };
+    assert_eq!(baseline.change(&slower), Some(0.5));
+}
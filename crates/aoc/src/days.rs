@@ -0,0 +1,196 @@
+use std::{
+    fmt,
+    panic::{self, AssertUnwindSafe},
+    str::FromStr,
+    time::{Duration, Instant},
+};
+
+use aoc_core::{input::InputSource, Solution};
+
+use crate::bench::{bench, Measurement};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const ALL: [Part; 2] = [Part::One, Part::Two];
+
+    // The name used for this part in files like baselines and answers.
+    pub fn key(self) -> &'static str {
+        match self {
+            Part::One => "part1",
+            Part::Two => "part2",
+        }
+    }
+
+    pub fn number(self) -> u8 {
+        match self {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+}
+
+impl FromStr for Part {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "1" => Ok(Part::One),
+            "2" => Ok(Part::Two),
+            _ => Err(format!("invalid part `{s}`, expected 1 or 2")),
+        }
+    }
+}
+
+impl fmt::Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad(match self {
+            Part::One => "1",
+            Part::Two => "2",
+        })
+    }
+}
+
+#[derive(Debug)]
+pub struct Outcome {
+    pub day: u8,
+    pub part: Part,
+    pub answer: Result<String, String>,
+    pub parse_time: Duration,
+    pub solve_time: Duration,
+}
+
+type BenchFn = fn(&str, &[Part], usize) -> Result<Vec<Measurement>, String>;
+
+pub struct Day {
+    pub number: u8,
+    pub run: fn(&str, &[Part]) -> Vec<Outcome>,
+    pub bench: BenchFn,
+}
+
+impl Day {
+    const fn new<S: Solution>() -> Self {
+        Day {
+            number: S::DAY,
+            run: run::<S>,
+            bench: bench::<S>,
+        }
+    }
+
+    pub fn find(number: u8) -> Option<&'static Day> {
+        DAYS.iter().find(|day| day.number == number)
+    }
+
+    pub fn solve(&self, source: &InputSource, parts: &[Part]) -> Vec<Outcome> {
+        match source.read(self.number) {
+            Ok(input) => (self.run)(&input, parts),
+            Err(e) => parts
+                .iter()
+                .map(|&part| Outcome {
+                    day: self.number,
+                    part,
+                    answer: Err(e.to_string()),
+                    parse_time: Duration::ZERO,
+                    solve_time: Duration::ZERO,
+                })
+                .collect(),
+        }
+    }
+}
+
+// Run a stage of a solution, turning a panic into an error message so a single
+// broken day doesn't take down the rest of the run. The panic is reported
+// along with the other results, so its message isn't printed as it happens,
+// but panics anywhere else still are.
+pub fn catch<T>(stage: impl FnOnce() -> T) -> Result<T, String> {
+    let hook = panic::take_hook();
+    panic::set_hook(Box::new(|_| {}));
+    let result = panic::catch_unwind(AssertUnwindSafe(stage));
+    panic::set_hook(hook);
+
+    result.map_err(|payload| {
+        if let Some(message) = payload.downcast_ref::<&str>() {
+            message.to_string()
+        } else if let Some(message) = payload.downcast_ref::<String>() {
+            message.clone()
+        } else {
+            "unknown panic".to_string()
+        }
+    })
+}
+
+fn run<S: Solution>(input: &str, parts: &[Part]) -> Vec<Outcome> {
+    let start = Instant::now();
+    let parsed = catch(|| S::parse(input))
+        .and_then(|parsed| parsed.map_err(|e| format!("{e:#}")))
+        .map_err(|e| format!("failed to parse input: {e}"));
+    let parse_time = start.elapsed();
+
+    parts
+        .iter()
+        .map(|&part| {
+            let start = Instant::now();
+            let answer = match &parsed {
+                Ok(parsed) => catch(|| match part {
+                    Part::One => S::try_part1(parsed).map(|answer| answer.to_string()),
+                    Part::Two => S::try_part2(parsed).map(|answer| answer.to_string()),
+                })
+                .and_then(|answer| answer.map_err(|e| format!("{e:#}"))),
+                Err(e) => Err(e.clone()),
+            };
+
+            Outcome {
+                day: S::DAY,
+                part,
+                answer,
+                parse_time,
+                solve_time: start.elapsed(),
+            }
+        })
+        .collect()
+}
+
+pub const DAYS: &[Day] = &[
+    Day::new::<day1::Day1>(),
+    Day::new::<day2::Day2>(),
+    Day::new::<day3::Day3>(),
+    Day::new::<day4::Day4>(),
+    Day::new::<day5::Day5>(),
+    Day::new::<day6::Day6>(),
+    Day::new::<day7::Day7>(),
+    Day::new::<day8::Day8>(),
+    Day::new::<day9::Day9>(),
+    Day::new::<day10::Day10>(),
+    Day::new::<day11::Day11>(),
+    Day::new::<day12::Day12>(),
+    Day::new::<day13::Day13>(),
+    Day::new::<day14::Day14>(),
+    Day::new::<day15::Day15>(),
+    Day::new::<day16::Day16>(),
+];
+
+#[test]
+fn catch_restores_panic_hook() {
+    use std::cell::Cell;
+
+    thread_local! {
+        static PANICKED: Cell<bool> = const { Cell::new(false) };
+    }
+
+    panic::set_hook(Box::new(|_| PANICKED.with(|panicked| panicked.set(true))));
+
+    assert_eq!(
+        catch(|| panic!("broken")),
+        Err::<(), _>("broken".to_string())
+    );
+    assert!(!PANICKED.with(Cell::get));
+
+    let _ = panic::catch_unwind(|| panic!("elsewhere"));
+    assert!(PANICKED.with(Cell::get));
+
+    let _ = panic::take_hook();
+}
@@ -0,0 +1,267 @@
+use std::{path::PathBuf, process::ExitCode, str::FromStr};
+
+use aoc_core::input::InputSource;
+use clap::{Args, Parser, Subcommand};
+
+use bench::Baseline;
+use days::{Day, Part, DAYS};
+use output::Format;
+
+mod bench;
+mod days;
+mod output;
+mod scaffold;
+mod verify;
+
+#[derive(Debug, Parser)]
+#[command(name = "aoc", about = "Advent of Code 2023 solutions")]
+struct Cli {
+    /// Directory containing the puzzle inputs as `day1.txt`, `day2.txt`, etc.
+    /// Defaults to $AOC_INPUT_DIR, falling back to each day's `src/input.txt`
+    #[arg(long, global = true)]
+    input_dir: Option<PathBuf>,
+
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Debug, Subcommand)]
+enum Command {
+    /// Run the solutions for a single day, or for all of them
+    Run {
+        #[command(flatten)]
+        selection: Selection,
+
+        /// How to print the answers and timings
+        #[arg(long, value_enum, default_value_t = Format::Table)]
+        format: Format,
+    },
+    /// Time the parse and solve stages of a single day, or of all of them
+    Bench {
+        #[command(flatten)]
+        selection: Selection,
+
+        /// How many times to run each stage
+        #[arg(long, short = 'n', default_value_t = 10, value_parser = clap::value_parser!(u32).range(1..))]
+        iterations: u32,
+
+        /// Compare the timings against a baseline saved by an earlier run
+        #[arg(long)]
+        baseline: Option<PathBuf>,
+
+        /// Save the timings as a baseline for later runs to compare against
+        #[arg(long)]
+        save_baseline: Option<PathBuf>,
+
+        /// How much slower than the baseline a stage may get, in percent,
+        /// before it's flagged as a regression
+        #[arg(long, default_value_t = 10.0)]
+        threshold: f64,
+    },
+    /// Check the answers of a single day, or of all of them, against the
+    /// answers recorded next to the inputs
+    Verify {
+        #[command(flatten)]
+        selection: Selection,
+
+        /// Record the answers for parts that don't have a recorded answer yet
+        #[arg(long)]
+        record: bool,
+    },
+    /// Create the crate for a new day from a template and add it to the
+    /// workspace
+    New {
+        /// The day to create (e.g. `17`)
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+    },
+}
+
+#[derive(Debug, Args)]
+struct Selection {
+    /// The day to run (e.g. `5`), or `all`
+    day: DaySelection,
+
+    /// Only run this part of the puzzle (1 or 2)
+    #[arg(long)]
+    part: Option<Part>,
+
+    /// Read the input from this file instead, or from stdin if `-`. Only
+    /// valid when running a single day
+    #[arg(long, short)]
+    input: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum DaySelection {
+    All,
+    Day(u8),
+}
+
+impl FromStr for DaySelection {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s == "all" {
+            return Ok(DaySelection::All);
+        }
+
+        let number = s
+            .parse()
+            .map_err(|_| format!("invalid day `{s}`, expected a number or `all`"))?;
+        if Day::find(number).is_none() {
+            return Err(format!("there is no solution for day {number}"));
+        }
+
+        Ok(DaySelection::Day(number))
+    }
+}
+
+impl Selection {
+    fn days(&self) -> Vec<&'static Day> {
+        match self.day {
+            DaySelection::All => DAYS.iter().collect(),
+            DaySelection::Day(number) => Day::find(number).into_iter().collect(),
+        }
+    }
+
+    fn parts(&self) -> Vec<Part> {
+        match self.part {
+            Some(part) => vec![part],
+            None => Part::ALL.to_vec(),
+        }
+    }
+
+    fn source(&self, default_source: InputSource) -> Result<InputSource, String> {
+        match (self.day, &self.input) {
+            (DaySelection::All, Some(_)) => {
+                Err("--input can only be used when running a single day".to_string())
+            }
+            (_, Some(input)) => Ok(InputSource::from_arg(input)),
+            (_, None) => Ok(default_source),
+        }
+    }
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+
+    let default_source = cli.input_dir.map_or(InputSource::Default, InputSource::Dir);
+
+    let result = match cli.command {
+        Command::Run { selection, format } => run(&selection, default_source, format),
+        Command::Bench {
+            selection,
+            iterations,
+            baseline,
+            save_baseline,
+            threshold,
+        } => bench(
+            &selection,
+            default_source,
+            iterations as usize,
+            baseline,
+            save_baseline,
+            threshold / 100.0,
+        ),
+        Command::Verify { selection, record } => verify(&selection, default_source, record),
+        Command::New { day } => new_day(day),
+    };
+
+    match result {
+        Ok(true) => ExitCode::SUCCESS,
+        Ok(false) => ExitCode::FAILURE,
+        Err(e) => {
+            eprintln!("error: {e}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+// Returns whether all selected days ran successfully.
+fn run(selection: &Selection, default_source: InputSource, format: Format) -> Result<bool, String> {
+    let source = selection.source(default_source)?;
+    let parts = selection.parts();
+
+    let outcomes = selection
+        .days()
+        .into_iter()
+        .flat_map(|day| day.solve(&source, &parts))
+        .collect::<Vec<_>>();
+    output::print(&outcomes, format)?;
+
+    Ok(outcomes.iter().all(|outcome| outcome.answer.is_ok()))
+}
+
+// Returns whether all selected days ran successfully, without getting slower
+// than the baseline.
+fn bench(
+    selection: &Selection,
+    default_source: InputSource,
+    iterations: usize,
+    baseline: Option<PathBuf>,
+    save_baseline: Option<PathBuf>,
+    threshold: f64,
+) -> Result<bool, String> {
+    let source = selection.source(default_source)?;
+    let parts = selection.parts();
+    let baseline = baseline.map(|path| Baseline::load(&path)).transpose()?;
+
+    let mut measurements = Vec::new();
+    let mut errors = Vec::new();
+    for day in selection.days() {
+        let result = source
+            .read(day.number)
+            .map_err(|e| e.to_string())
+            .and_then(|input| (day.bench)(&input, &parts, iterations));
+        match result {
+            Ok(day_measurements) => measurements.extend(day_measurements),
+            Err(e) => errors.push(format!("day {}: {e}", day.number)),
+        }
+    }
+
+    let regressed = bench::print_report(&measurements, baseline.as_ref(), threshold);
+    for e in &errors {
+        eprintln!("\n{e}");
+    }
+
+    if let Some(path) = save_baseline {
+        Baseline::save(&path, &measurements)
+            .map_err(|e| format!("failed to save baseline `{}`: {e}", path.display()))?;
+    }
+
+    Ok(errors.is_empty() && !regressed)
+}
+
+// Returns whether every answer matches the recorded one, or was just
+// recorded.
+fn verify(
+    selection: &Selection,
+    default_source: InputSource,
+    record: bool,
+) -> Result<bool, String> {
+    let source = selection.source(default_source)?;
+    let parts = selection.parts();
+
+    let verifications = selection
+        .days()
+        .into_iter()
+        .flat_map(|day| verify::verify(day, &source, &parts, record))
+        .collect::<Vec<_>>();
+    verify::print_report(&verifications);
+
+    Ok(verifications
+        .iter()
+        .all(|verification| verification.passed()))
+}
+
+fn new_day(day: u8) -> Result<bool, String> {
+    let dir = scaffold::new_day(&scaffold::workspace_root(), day)?;
+    println!("Created day {day} in `{}`", dir.display());
+    println!(
+        "Put the puzzle input in `{}`",
+        dir.join("src").join("input.txt").display()
+    );
+
+    Ok(true)
+}
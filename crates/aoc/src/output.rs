@@ -0,0 +1,156 @@
+use std::{error::Error, io};
+
+use clap::ValueEnum;
+use serde::Serialize;
+
+use crate::days::Outcome;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum Format {
+    /// A table, with any errors below it
+    Table,
+    /// An array of `{day, part, answer, error, parse_ns, solve_ns}` records
+    Json,
+    /// The same records as CSV, with a header
+    Csv,
+}
+
+// An outcome in a shape meant for scripts. `answer` is missing if the part
+// failed, in which case `error` says why.
+#[derive(Debug, Serialize)]
+struct Record<'a> {
+    day: u8,
+    part: u8,
+    answer: Option<&'a str>,
+    error: Option<&'a str>,
+    parse_ns: u128,
+    solve_ns: u128,
+}
+
+impl<'a> From<&'a Outcome> for Record<'a> {
+    fn from(outcome: &'a Outcome) -> Self {
+        Record {
+            day: outcome.day,
+            part: outcome.part.number(),
+            answer: outcome.answer.as_deref().ok(),
+            error: outcome.answer.as_ref().err().map(String::as_str),
+            parse_ns: outcome.parse_time.as_nanos(),
+            solve_ns: outcome.solve_time.as_nanos(),
+        }
+    }
+}
+
+pub fn print(outcomes: &[Outcome], format: Format) -> Result<(), String> {
+    match format {
+        Format::Table => {
+            print_table(outcomes);
+            Ok(())
+        }
+        Format::Json | Format::Csv => write_records(outcomes, format, io::stdout().lock())
+            .map_err(|e| format!("failed to write the results: {e}")),
+    }
+}
+
+fn write_records(
+    outcomes: &[Outcome],
+    format: Format,
+    mut writer: impl io::Write,
+) -> Result<(), Box<dyn Error>> {
+    let records = outcomes.iter().map(Record::from);
+
+    match format {
+        Format::Table => unreachable!("tables aren't made of records"),
+        Format::Json => {
+            serde_json::to_writer_pretty(&mut writer, &records.collect::<Vec<_>>())?;
+            writeln!(writer)?;
+        }
+        Format::Csv => {
+            let mut writer = csv::Writer::from_writer(writer);
+            for record in records {
+                writer.serialize(record)?;
+            }
+            writer.flush()?;
+        }
+    }
+
+    Ok(())
+}
+
+fn print_table(outcomes: &[Outcome]) {
+    let answers = outcomes
+        .iter()
+        .map(|outcome| match &outcome.answer {
+            Ok(answer) => answer.as_str(),
+            Err(_) => "error",
+        })
+        .collect::<Vec<_>>();
+    let answer_width = answers.iter().map(|a| a.len()).max().unwrap_or(0).max(6);
+
+    println!(
+        "{:>3}  {:>4}  {:<answer_width$}  {:>10}  {:>10}",
+        "day", "part", "answer", "parse", "solve"
+    );
+    for (outcome, answer) in outcomes.iter().zip(&answers) {
+        println!(
+            "{:>3}  {:>4}  {:<answer_width$}  {:>10}  {:>10}",
+            outcome.day,
+            outcome.part,
+            answer,
+            format!("{:.2?}", outcome.parse_time),
+            format!("{:.2?}", outcome.solve_time),
+        );
+    }
+
+    // Print the errors below the table, only once per day if both parts failed
+    // for the same reason (e.g. a missing input).
+    let mut previous: Option<(u8, &str)> = None;
+    for outcome in outcomes {
+        if let Err(e) = &outcome.answer {
+            if previous != Some((outcome.day, e)) {
+                eprintln!("\nday {} part {}: {e}", outcome.day, outcome.part);
+            }
+            previous = Some((outcome.day, e));
+        }
+    }
+}
+
+#[test]
+fn output_records() {
+    use std::time::Duration;
+
+    use crate::days::Part;
+
+    let outcomes = [
+        Outcome {
+            day: 7,
+            part: Part::One,
+            answer: Ok("6440".to_string()),
+            parse_time: Duration::from_nanos(1500),
+            solve_time: Duration::from_nanos(200),
+        },
+        Outcome {
+            day: 7,
+            part: Part::Two,
+            answer: Err("no input for day 7".to_string()),
+            parse_time: Duration::ZERO,
+            solve_time: Duration::ZERO,
+        },
+    ];
+
+    let mut csv = Vec::new();
+    write_records(&outcomes, Format::Csv, &mut csv).unwrap();
+    assert_eq!(
+        String::from_utf8(csv).unwrap(),
+        "day,part,answer,error,parse_ns,solve_ns
+7,1,6440,,1500,200
+7,2,,no input for day 7,0,0
+"
+    );
+
+    let mut json = Vec::new();
+    write_records(&outcomes, Format::Json, &mut json).unwrap();
+    let json = serde_json::from_slice::<serde_json::Value>(&json).unwrap();
+    assert_eq!(json[0]["answer"], "6440");
+    assert_eq!(json[1]["answer"], serde_json::Value::Null);
+    assert_eq!(json[1]["error"], "no input for day 7");
+}
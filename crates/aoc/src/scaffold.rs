@@ -0,0 +1,291 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+// The workspace this runner was built from, which new days are added to.
+pub fn workspace_root() -> PathBuf {
+    let runner = Path::new(env!("CARGO_MANIFEST_DIR"));
+    runner.ancestors().nth(2).unwrap_or(runner).to_path_buf()
+}
+
+// Create `crates/dayN` from the template, add it to the workspace members and
+// register it with the runner. Returns the directory of the new crate.
+pub fn new_day(root: &Path, day: u8) -> Result<PathBuf, String> {
+    let dir = root.join("crates").join(format!("day{day}"));
+    if dir.exists() {
+        return Err(format!(
+            "`{}` already exists, refusing to overwrite it",
+            dir.display()
+        ));
+    }
+
+    // Work out all the edits before touching anything, so a workspace we don't
+    // understand is left as it was.
+    let workspace_manifest = root.join("Cargo.toml");
+    let runner_manifest = root.join("crates").join("aoc").join("Cargo.toml");
+    let runner_days = root.join("crates").join("aoc").join("src").join("days.rs");
+    let edits = [
+        (
+            &workspace_manifest,
+            add_member as fn(&str, u8) -> Option<String>,
+        ),
+        (&runner_manifest, add_dependency),
+        (&runner_days, add_day),
+    ]
+    .into_iter()
+    .map(|(path, edit)| {
+        let contents = read(path)?;
+        let edited = edit(&contents, day).ok_or_else(|| {
+            format!(
+                "don't know where to register day {day} in `{}`",
+                path.display()
+            )
+        })?;
+        Ok((path, edited))
+    })
+    .collect::<Result<Vec<_>, String>>()?;
+
+    let src = dir.join("src");
+    fs::create_dir_all(&src).map_err(|e| format!("failed to create `{}`: {e}", src.display()))?;
+    for (path, contents) in [
+        (dir.join("Cargo.toml"), cargo_toml(day)),
+        (src.join("lib.rs"), lib_rs(day)),
+        (src.join("main.rs"), main_rs(day)),
+    ] {
+        write(&path, &contents)?;
+    }
+    for (path, contents) in edits {
+        write(path, &contents)?;
+    }
+
+    Ok(dir)
+}
+
+fn read(path: &Path) -> Result<String, String> {
+    fs::read_to_string(path).map_err(|e| format!("failed to read `{}`: {e}", path.display()))
+}
+
+fn write(path: &Path, contents: &str) -> Result<(), String> {
+    fs::write(path, contents).map_err(|e| format!("failed to write `{}`: {e}", path.display()))
+}
+
+// Insert `line` before the first line at or after `start` that starts with
+// `end`. Returns `None` if there's no such line, or if `line` is already there.
+fn insert_before(contents: &str, start: &str, end: &str, line: &str) -> Option<String> {
+    let start = contents.find(start)?;
+    if contents[start..]
+        .lines()
+        .any(|existing| existing.trim() == line.trim())
+    {
+        return None;
+    }
+
+    let offset = contents[start..]
+        .split_inclusive('\n')
+        .take_while(|existing| !existing.trim_start().starts_with(end))
+        .map(str::len)
+        .sum::<usize>();
+    if start + offset == contents.len() {
+        return None;
+    }
+
+    let mut contents = contents.to_string();
+    contents.insert_str(start + offset, &format!("{line}\n"));
+    Some(contents)
+}
+
+fn add_member(manifest: &str, day: u8) -> Option<String> {
+    insert_before(
+        manifest,
+        "members = [",
+        "]",
+        &format!("    \"crates/day{day}\","),
+    )
+}
+
+fn add_day(days: &str, day: u8) -> Option<String> {
+    insert_before(
+        days,
+        "pub const DAYS",
+        "];",
+        &format!("    Day::new::<day{day}::Day{day}>(),"),
+    )
+}
+
+// The days are listed in order among the other dependencies, so the new one
+// goes after the last of them.
+fn add_dependency(manifest: &str, day: u8) -> Option<String> {
+    let line = format!("day{day} = {{ path = \"../day{day}\" }}");
+    let start = manifest.find("[dependencies]")?;
+    if manifest[start..].lines().any(|existing| existing == line) {
+        return None;
+    }
+
+    let mut offset = start + manifest[start..].find('\n')? + 1;
+    let mut after_days = None;
+    for existing in manifest[offset..].split_inclusive('\n') {
+        if existing.starts_with('[') {
+            break;
+        }
+        offset += existing.len();
+        if existing.starts_with("day") {
+            after_days = Some(offset);
+        }
+    }
+
+    let mut manifest = manifest.to_string();
+    manifest.insert_str(after_days?, &format!("{line}\n"));
+    Some(manifest)
+}
+
+fn cargo_toml(day: u8) -> String {
+    format!(
+        r#"[package]
+name = "day{day}"
+version = "0.1.0"
+edition = "2021"
+
+# See more keys and their definitions at https://doc.rust-lang.org/cargo/reference/manifest.html
+
+[dependencies]
+aoc-core = {{ path = "../aoc-core" }}
+"#
+    )
+}
+
+fn lib_rs(day: u8) -> String {
+    format!(
+        r#"use aoc_core::{{Error, Solution}};
+
+pub struct Day{day};
+
+impl Solution for Day{day} {{
+    const DAY: u8 = {day};
+
+    type Input = Vec<String>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {{
+        parse(input)
+    }}
+
+    fn part1(input: &Self::Input) -> u64 {{
+        Self::try_part1(input).unwrap_or_else(|e| panic!("{{e}}"))
+    }}
+
+    fn part2(input: &Self::Input) -> u64 {{
+        Self::try_part2(input).unwrap_or_else(|e| panic!("{{e}}"))
+    }}
+
+    fn try_part1(input: &Self::Input) -> Result<u64, Error> {{
+        part1(input)
+    }}
+
+    fn try_part2(input: &Self::Input) -> Result<u64, Error> {{
+        part2(input)
+    }}
+}}
+
+pub fn parse(input: &str) -> Result<Vec<String>, Error> {{
+    Ok(input.lines().map(str::to_string).collect())
+}}
+
+pub fn part1(_input: &[String]) -> Result<u64, Error> {{
+    Err(Error::new("day {day} part 1 is not solved yet"))
+}}
+
+pub fn part2(_input: &[String]) -> Result<u64, Error> {{
+    Err(Error::new("day {day} part 2 is not solved yet"))
+}}
+
+// Paste in the puzzle's example, and its answers below.
+#[cfg(test)]
+const TEST_INPUT: &str = "";
+
+#[test]
+#[ignore = "day {day} part 1 is not solved yet"]
+fn day{day}_part1() {{
+    let input = parse(TEST_INPUT).unwrap();
+    assert_eq!(part1(&input), Ok(u64::MAX));
+}}
+
+#[test]
+#[ignore = "day {day} part 2 is not solved yet"]
+fn day{day}_part2() {{
+    let input = parse(TEST_INPUT).unwrap();
+    assert_eq!(part2(&input), Ok(u64::MAX));
+}}
+"#
+    )
+}
+
+fn main_rs(day: u8) -> String {
+    format!(
+        r#"use aoc_core::Solution;
+use day{day}::Day{day};
+
+fn main() {{
+    let input = aoc_core::input::from_args(Day{day}::DAY);
+    let input = aoc_core::parse_or_exit::<Day{day}>(&input);
+
+    match Day{day}::try_part1(&input) {{
+        Ok(answer) => println!("Part 1: {{answer}}"),
+        Err(e) => eprintln!("Part 1: {{e}}"),
+    }}
+    match Day{day}::try_part2(&input) {{
+        Ok(answer) => println!("Part 2: {{answer}}"),
+        Err(e) => eprintln!("Part 2: {{e}}"),
+    }}
+}}
+"#
+    )
+}
+
+#[test]
+fn scaffold_new_day() {
+    // Each run gets its own directory, so runs at the same time don't clash.
+    let root = std::env::temp_dir().join(format!("aoc-scaffold-new-day-{}", std::process::id()));
+    fs::create_dir_all(root.join("crates/aoc/src")).unwrap();
+    fs::write(
+        root.join("Cargo.toml"),
+        "[workspace]\nmembers = [\n    \"crates/aoc\",\n    \"crates/day1\",\n]\n",
+    )
+    .unwrap();
+    fs::write(
+        root.join("crates/aoc/Cargo.toml"),
+        "[package]\nname = \"aoc\"\n\n[dependencies]\nclap = \"4\"\nday1 = { path = \"../day1\" }\ntoml = \"1\"\n",
+    )
+    .unwrap();
+    fs::write(
+        root.join("crates/aoc/src/days.rs"),
+        "pub const DAYS: &[Day] = &[\n    Day::new::<day1::Day1>(),\n];\n",
+    )
+    .unwrap();
+
+    let dir = new_day(&root, 2).unwrap();
+    assert!(dir.join("src/lib.rs").exists());
+    let lib_rs = fs::read_to_string(dir.join("src/lib.rs")).unwrap();
+    assert!(lib_rs.contains("fn day2_part2()"));
+    // Until the puzzle is solved, the parts are errors rather than made-up
+    // answers, and their tests are ignored.
+    assert!(lib_rs.contains("Err(Error::new(\"day 2 part 1 is not solved yet\"))"));
+    assert!(lib_rs.contains("#[ignore = \"day 2 part 2 is not solved yet\"]"));
+    assert_eq!(
+        fs::read_to_string(root.join("Cargo.toml")).unwrap(),
+        "[workspace]\nmembers = [\n    \"crates/aoc\",\n    \"crates/day1\",\n    \"crates/day2\",\n]\n"
+    );
+    assert_eq!(
+        fs::read_to_string(root.join("crates/aoc/Cargo.toml")).unwrap(),
+        "[package]\nname = \"aoc\"\n\n[dependencies]\nclap = \"4\"\nday1 = { path = \"../day1\" }\nday2 = { path = \"../day2\" }\ntoml = \"1\"\n"
+    );
+    assert_eq!(
+        fs::read_to_string(root.join("crates/aoc/src/days.rs")).unwrap(),
+        "pub const DAYS: &[Day] = &[\n    Day::new::<day1::Day1>(),\n    Day::new::<day2::Day2>(),\n];\n"
+    );
+
+    assert!(new_day(&root, 2).unwrap_err().contains("already exists"));
+
+    fs::remove_dir_all(&root).unwrap();
+}
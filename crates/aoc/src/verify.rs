@@ -0,0 +1,225 @@
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use aoc_core::input::InputSource;
+use toml::{Table, Value};
+
+use crate::days::{Day, Part};
+
+// The recorded answers for a day, e.g.:
+//
+// part1 = "55172"
+// part2 = "54925"
+#[derive(Debug)]
+pub struct Answers {
+    path: PathBuf,
+    table: Table,
+}
+
+impl Answers {
+    // Load the answers from `path`. A missing file just means no answers have
+    // been recorded yet.
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let table = match fs::read_to_string(path) {
+            Ok(contents) => contents
+                .parse::<Table>()
+                .map_err(|e| format!("invalid answers file `{}`: {e}", path.display()))?,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Table::new(),
+            Err(e) => return Err(format!("failed to read `{}`: {e}", path.display())),
+        };
+
+        Ok(Answers {
+            path: path.to_path_buf(),
+            table,
+        })
+    }
+
+    pub fn get(&self, part: Part) -> Result<Option<String>, String> {
+        match self.table.get(part.key()) {
+            None => Ok(None),
+            Some(Value::String(answer)) => Ok(Some(answer.clone())),
+            Some(Value::Integer(answer)) => Ok(Some(answer.to_string())),
+            Some(value) => Err(format!(
+                "`{}` in `{}` should be a string or an integer, found {}",
+                part.key(),
+                self.path.display(),
+                value.type_str()
+            )),
+        }
+    }
+
+    pub fn set(&mut self, part: Part, answer: &str) {
+        self.table
+            .insert(part.key().to_string(), Value::String(answer.to_string()));
+    }
+
+    pub fn save(&self) -> Result<(), String> {
+        fs::write(&self.path, self.table.to_string())
+            .map_err(|e| format!("failed to write `{}`: {e}", self.path.display()))
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Status {
+    Correct,
+    Wrong { expected: String },
+    // There's no recorded answer to compare against.
+    Missing,
+    // There was no recorded answer, so the actual answer was recorded.
+    Recorded,
+}
+
+#[derive(Debug)]
+pub struct Verification {
+    pub day: u8,
+    pub part: Part,
+    pub result: Result<(String, Status), String>,
+}
+
+impl Verification {
+    // A missing answer fails, as nothing was verified: a wrong input
+    // directory shouldn't look like success. Record it with `--record`.
+    pub fn passed(&self) -> bool {
+        matches!(self.result, Ok((_, Status::Correct | Status::Recorded)))
+    }
+}
+
+pub fn verify(day: &Day, source: &InputSource, parts: &[Part], record: bool) -> Vec<Verification> {
+    let failed = |e: &str| {
+        parts
+            .iter()
+            .map(|&part| Verification {
+                day: day.number,
+                part,
+                result: Err(e.to_string()),
+            })
+            .collect()
+    };
+
+    let mut answers = match source.answers_path(day.number) {
+        Some(path) => match Answers::load(&path) {
+            Ok(answers) => Some(answers),
+            Err(e) => return failed(&e),
+        },
+        None if record => return failed("can't record answers for input read from stdin"),
+        None => None,
+    };
+
+    let verifications = day
+        .solve(source, parts)
+        .into_iter()
+        .map(|outcome| {
+            let result = outcome.answer.and_then(|actual| {
+                let expected = match &answers {
+                    Some(answers) => answers.get(outcome.part)?,
+                    None => None,
+                };
+                let status = match (expected, &mut answers) {
+                    (Some(expected), _) if expected == actual => Status::Correct,
+                    (Some(expected), _) => Status::Wrong { expected },
+                    (None, Some(answers)) if record => {
+                        answers.set(outcome.part, &actual);
+                        Status::Recorded
+                    }
+                    (None, _) => Status::Missing,
+                };
+
+                Ok((actual, status))
+            });
+
+            Verification {
+                day: outcome.day,
+                part: outcome.part,
+                result,
+            }
+        })
+        .collect::<Vec<_>>();
+
+    let recorded = verifications
+        .iter()
+        .any(|verification| matches!(verification.result, Ok((_, Status::Recorded))));
+    if let (true, Some(answers)) = (recorded, &answers) {
+        if let Err(e) = answers.save() {
+            return failed(&e);
+        }
+    }
+
+    verifications
+}
+
+pub fn print_report(verifications: &[Verification]) {
+    println!(
+        "{:>3}  {:>4}  {:<8}  {:<16}  {:<16}",
+        "day", "part", "status", "expected", "actual"
+    );
+    for verification in verifications {
+        let (status, expected, actual) = match &verification.result {
+            Ok((actual, Status::Correct)) => ("ok", actual.as_str(), actual.as_str()),
+            Ok((actual, Status::Wrong { expected })) => {
+                ("WRONG", expected.as_str(), actual.as_str())
+            }
+            Ok((actual, Status::Missing)) => ("missing", "", actual.as_str()),
+            Ok((actual, Status::Recorded)) => ("recorded", "", actual.as_str()),
+            Err(_) => ("error", "", ""),
+        };
+
+        println!(
+            "{:>3}  {:>4}  {:<8}  {:<16}  {:<16}",
+            verification.day, verification.part, status, expected, actual
+        );
+    }
+
+    if verifications
+        .iter()
+        .any(|verification| matches!(verification.result, Ok((_, Status::Missing))))
+    {
+        eprintln!("\nsome answers are missing, run with `--record` to record them");
+    }
+
+    let mut previous: Option<(u8, &str)> = None;
+    for verification in verifications {
+        if let Err(e) = &verification.result {
+            if previous != Some((verification.day, e)) {
+                eprintln!("\nday {} part {}: {e}", verification.day, verification.part);
+            }
+            previous = Some((verification.day, e));
+        }
+    }
+}
+
+#[test]
+fn verify_answers_file() {
+    let path = std::env::temp_dir().join("aoc-verify-answers-file.toml");
+    fs::write(&path, "part1 = \"55172\"\npart2 = 54925\n").unwrap();
+
+    let mut answers = Answers::load(&path).unwrap();
+    assert_eq!(answers.get(Part::One), Ok(Some("55172".to_string())));
+    assert_eq!(answers.get(Part::Two), Ok(Some("54925".to_string())));
+
+    answers.table.remove("part2");
+    assert_eq!(answers.get(Part::Two), Ok(None));
+    answers.set(Part::Two, "281");
+    answers.save().unwrap();
+
+    let answers = Answers::load(&path).unwrap();
+    assert_eq!(answers.get(Part::Two), Ok(Some("281".to_string())));
+}
+
+#[test]
+fn verify_missing_answers_fail() {
+    let verification = |status| Verification {
+        day: 1,
+        part: Part::One,
+        result: Ok(("55172".to_string(), status)),
+    };
+
+    assert!(verification(Status::Correct).passed());
+    assert!(verification(Status::Recorded).passed());
+    assert!(!verification(Status::Missing).passed());
+    assert!(!verification(Status::Wrong {
+        expected: "1".to_string()
+    })
+    .passed());
+}
@@ -0,0 +1,112 @@
+use std::collections::HashMap;
+
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use day1::{lines_sum_complex, lines_sum_simple, par_lines_sum, Matcher, Vocabulary};
+
+// Lines of letters with digit names and numerals mixed in, like the puzzle
+// input but as many as we like.
+fn synthetic_input(lines: usize) -> String {
+    const PIECES: [&str; 16] = [
+        "one", "two", "three", "four", "five", "six", "seven", "eight", "nine", "eightwo",
+        "oneight", "xk", "qzr", "t", "fiv", "sevven",
+    ];
+
+    // Xorshift, so the input is the same on every run.
+    let mut seed = 0x2545_f491_4f6c_dd1d_u64;
+    let mut random = move |n: usize| {
+        seed ^= seed << 13;
+        seed ^= seed >> 7;
+        seed ^= seed << 17;
+        (seed % n as u64) as usize
+    };
+
+    let mut input = String::new();
+    for _ in 0..lines {
+        input.push(char::from(b'1' + random(9) as u8));
+        for _ in 0..random(12) {
+            match random(4) {
+                0 => input.push(char::from(b'0' + random(10) as u8)),
+                _ => input.push_str(PIECES[random(PIECES.len())]),
+            }
+        }
+        input.push('\n');
+    }
+
+    input
+}
+
+// The previous approach, which tries every digit name at every position.
+fn suffix_scan(input: &str) -> u32 {
+    let digit_names = HashMap::from([
+        ("one", 1),
+        ("two", 2),
+        ("three", 3),
+        ("four", 4),
+        ("five", 5),
+        ("six", 6),
+        ("seven", 7),
+        ("eight", 8),
+        ("nine", 9),
+    ]);
+
+    input
+        .lines()
+        .map(|line| {
+            let mut remaining_str = line;
+            let mut digits = Vec::new();
+
+            'outer: while !remaining_str.is_empty() {
+                for (name, digit) in &digit_names {
+                    if remaining_str.starts_with(name) {
+                        digits.push(*digit);
+
+                        remaining_str = &remaining_str[1..];
+                        continue 'outer;
+                    }
+                }
+
+                if let Some(digit) = remaining_str.chars().next().and_then(|c| c.to_digit(10)) {
+                    digits.push(digit);
+                }
+
+                remaining_str = &remaining_str[1..];
+            }
+
+            digits[0] * 10 + digits[digits.len() - 1]
+        })
+        .sum()
+}
+
+fn digits(c: &mut Criterion) {
+    let mut group = c.benchmark_group("digits");
+    for lines in [1_000, 100_000] {
+        let input = synthetic_input(lines);
+        assert_eq!(Ok(suffix_scan(&input)), lines_sum_complex(&input));
+
+        group.throughput(Throughput::Bytes(input.len() as u64));
+        group.bench_with_input(
+            BenchmarkId::new("suffix_scan", lines),
+            &input,
+            |b, input| b.iter(|| suffix_scan(input)),
+        );
+        group.bench_with_input(BenchmarkId::new("matcher", lines), &input, |b, input| {
+            b.iter(|| lines_sum_complex(input))
+        });
+        group.bench_with_input(
+            BenchmarkId::new("matcher_numerals", lines),
+            &input,
+            |b, input| b.iter(|| lines_sum_simple(input)),
+        );
+    }
+    let input = synthetic_input(1_000_000);
+    let matcher = Matcher::with_vocabulary(&Vocabulary::english());
+    group.throughput(Throughput::Bytes(input.len() as u64));
+    group.bench_function("par_reader/1000000", |b| {
+        b.iter(|| par_lines_sum(input.as_bytes(), &matcher))
+    });
+    group.bench_function("matcher/1000000", |b| b.iter(|| lines_sum_complex(&input)));
+    group.finish();
+}
+
+criterion_group!(benches, digits);
+criterion_main!(benches);
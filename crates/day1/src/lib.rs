@@ -0,0 +1,289 @@
+use std::{
+    io::{self, BufRead, Read},
+    str,
+};
+
+use aoc_core::{Error, Solution};
+use rayon::prelude::*;
+
+mod matcher;
+mod vocabulary;
+
+pub use matcher::{Kind, Match, Matcher};
+pub use vocabulary::Vocabulary;
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    const DAY: u8 = 1;
+
+    type Input = String;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    // Every line needs a digit, spelled out or not, for the second part to
+    // have an answer. Most lines have a numeral, so only the others are
+    // searched for words.
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        let matcher = Matcher::with_vocabulary(&Vocabulary::english());
+        let errors = input.lines().enumerate().filter_map(|(index, line)| {
+            let has_digit =
+                line.bytes().any(|b| b.is_ascii_digit()) || matcher.first(line).is_some();
+            (!has_digit).then(|| Error::in_line(index, line, 0..line.len(), "line has no digits"))
+        });
+
+        match Error::combine(errors) {
+            Some(e) => Err(e),
+            None => Ok(input.to_string()),
+        }
+    }
+
+    fn part1(input: &Self::Input) -> u32 {
+        Self::try_part1(input).unwrap_or_else(|e| panic!("{e}"))
+    }
+
+    fn part2(input: &Self::Input) -> u32 {
+        Self::try_part2(input).unwrap_or_else(|e| panic!("{e}"))
+    }
+
+    // Lines with only spelled out digits are fine for the second part, but
+    // the first one has no answer for them.
+    fn try_part1(input: &Self::Input) -> Result<u32, Error> {
+        lines_sum_simple(input)
+    }
+
+    fn try_part2(input: &Self::Input) -> Result<u32, Error> {
+        lines_sum_complex(input)
+    }
+}
+
+// The first and last digit on a line, which make up its calibration value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Calibration {
+    pub first: Match,
+    pub last: Match,
+}
+
+impl Calibration {
+    pub fn value(&self) -> u32 {
+        self.first.value * 10 + self.last.value
+    }
+}
+
+pub fn lines_sum_simple(input: &str) -> Result<u32, Error> {
+    lines_sum(input, &Matcher::numerals())
+}
+
+pub fn lines_sum_complex(input: &str) -> Result<u32, Error> {
+    lines_sum_words(input, &Vocabulary::english())
+}
+
+pub fn lines_sum_words(input: &str, vocabulary: &Vocabulary) -> Result<u32, Error> {
+    lines_sum(input, &Matcher::with_vocabulary(vocabulary))
+}
+
+// The sum of the calibration values of all lines, or the lines without any
+// digits.
+pub fn lines_sum(input: &str, matcher: &Matcher) -> Result<u32, Error> {
+    let mut sum = 0;
+    let mut errors = Vec::new();
+    for calibration in calibrations(input, matcher) {
+        match calibration {
+            Ok(calibration) => sum += calibration.value(),
+            Err(e) => errors.push(e),
+        }
+    }
+
+    match Error::combine(errors) {
+        Some(e) => Err(e),
+        None => Ok(sum),
+    }
+}
+
+pub fn calibrations<'a>(
+    input: &'a str,
+    matcher: &'a Matcher,
+) -> impl Iterator<Item = Result<Calibration, Error>> + 'a {
+    input.lines().enumerate().map(|(index, line)| {
+        calibrate(line, matcher)
+            .ok_or_else(|| Error::in_line(index, line, 0..line.len(), "line has no digits"))
+    })
+}
+
+// Like `lines_sum`, but reads the lines from `reader` a few megabytes at a
+// time and goes through each chunk in parallel, so the whole input never has
+// to be in memory.
+pub fn par_lines_sum(reader: impl BufRead, matcher: &Matcher) -> Result<u64, Error> {
+    par_lines_sum_in_chunks(reader, matcher, 1 << 22, MAX_ERRORS)
+}
+
+// The most lines without digits `par_lines_sum` reports. Past that, the input
+// is likely not a calibration document at all, and an error for each of its
+// lines would take as much memory as the input.
+const MAX_ERRORS: usize = 100;
+
+fn par_lines_sum_in_chunks(
+    mut reader: impl BufRead,
+    matcher: &Matcher,
+    chunk_size: usize,
+    max_errors: usize,
+) -> Result<u64, Error> {
+    let read_error = |e: io::Error| Error::new(format!("failed to read input: {e}"));
+
+    // Keep the first `max_errors` errors, and count the rest.
+    let keep = |(sum, mut errors, omitted): (u64, Vec<Error>, usize),
+                (other_sum, other_errors, other_omitted): (u64, Vec<Error>, usize)| {
+        errors.extend(other_errors);
+        let dropped = errors.len().saturating_sub(max_errors);
+        errors.truncate(max_errors);
+        (sum + other_sum, errors, omitted + dropped + other_omitted)
+    };
+
+    let mut totals = (0, Vec::new(), 0);
+    let mut buffer = Vec::new();
+    let mut first_index = 0;
+    loop {
+        // Read the rest of the last line too, so no line is split across
+        // chunks.
+        buffer.clear();
+        reader
+            .by_ref()
+            .take(chunk_size as u64)
+            .read_to_end(&mut buffer)
+            .map_err(read_error)?;
+        reader.read_until(b'\n', &mut buffer).map_err(read_error)?;
+        if buffer.is_empty() {
+            break;
+        }
+
+        let chunk = str::from_utf8(&buffer)
+            .map_err(|e| Error::new(format!("input is not valid UTF-8: {e}")))?;
+        let lines = chunk.lines().collect::<Vec<_>>();
+        let chunk_totals = lines
+            .par_iter()
+            .enumerate()
+            .map(|(index, line)| match calibrate(line, matcher) {
+                Some(calibration) => (u64::from(calibration.value()), Vec::new(), 0),
+                None => (
+                    0,
+                    vec![Error::in_line(
+                        first_index + index,
+                        line,
+                        0..line.len(),
+                        "line has no digits",
+                    )],
+                    0,
+                ),
+            })
+            .reduce(|| (0, Vec::new(), 0), keep);
+
+        totals = keep(totals, chunk_totals);
+        first_index += lines.len();
+    }
+
+    let (sum, errors, omitted) = totals;
+    match Error::combine(errors) {
+        Some(e) => Err(e.with_omitted(omitted)),
+        None => Ok(sum),
+    }
+}
+
+// Searches for the first digit from the start of the line and the last one
+// from the end.
+pub fn calibrate(line: &str, matcher: &Matcher) -> Option<Calibration> {
+    Some(Calibration {
+        first: matcher.first(line)?,
+        last: matcher.last(line)?,
+    })
+}
+
+#[test]
+fn day1_part1() {
+    assert_eq!(lines_sum_simple("1122"), Ok(12));
+    assert_eq!(
+        lines_sum_simple(
+            "1abc2
+pqr3stu8vwx
+a1b2c3d4e5f
+treb7uchet"
+        ),
+        Ok(142)
+    );
+}
+
+#[test]
+fn day1_part2() {
+    assert_eq!(
+        lines_sum_complex(
+            "two1nine
+eightwothree
+abcone2threexyz
+xtwone3four
+4nineeightseven2
+zoneight234
+7pqrstsixteen"
+        ),
+        Ok(281)
+    )
+}
+
+#[test]
+fn day1_parts() {
+    let input = Day1::parse("two1nine\neightwothree\n").unwrap();
+    assert_eq!(Day1::try_part2(&input), Ok(29 + 83));
+    assert_eq!(
+        Day1::try_part1(&input).unwrap_err().to_string(),
+        "line 2, column 1: line has no digits"
+    );
+
+    assert_eq!(
+        Day1::parse("1abc2\nxyz\n").unwrap_err().to_string(),
+        "line 2, column 1: line has no digits"
+    );
+}
+
+#[test]
+fn day1_calibrations() {
+    let matcher = Matcher::with_vocabulary(&Vocabulary::english());
+    let calibration = calibrate("xtwone3four", &matcher).unwrap();
+    assert_eq!(
+        (calibration.first.start, calibration.first.kind),
+        (1, Kind::Word)
+    );
+    assert_eq!((calibration.last.end, calibration.value()), (11, 24));
+
+    let error = lines_sum("7pqrst\n\nabc\n", &matcher).unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "line 2, column 1: line has no digits (and 1 more error)"
+    );
+    assert_eq!(
+        lines_sum_simple("one2\nthree\n").unwrap_err().to_string(),
+        "line 2, column 1: line has no digits"
+    );
+}
+
+#[test]
+fn day1_par_lines_sum() {
+    let input = "two1nine\neightwothree\nabc\nxtwone3four\n4nineeightseven2\nzoneight234\n";
+    let matcher = Matcher::with_vocabulary(&Vocabulary::english());
+
+    let error = par_lines_sum_in_chunks(input.as_bytes(), &matcher, 16, MAX_ERRORS).unwrap_err();
+    assert_eq!(error.to_string(), "line 3, column 1: line has no digits");
+
+    let error =
+        par_lines_sum_in_chunks("abc\n".repeat(50).as_bytes(), &matcher, 16, 3).unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "line 1, column 1: line has no digits (and 49 more errors)"
+    );
+    assert_eq!(error.others().len(), 2);
+    assert_eq!(error.others()[1].location().unwrap().line, 3);
+    assert_eq!(error.omitted(), 47);
+
+    let input = input.replace("abc\n", "").repeat(100);
+    assert_eq!(
+        par_lines_sum_in_chunks(input.as_bytes(), &matcher, 16, MAX_ERRORS),
+        lines_sum(&input, &matcher).map(u64::from)
+    );
+}
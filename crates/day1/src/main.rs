@@ -1,93 +1,94 @@
-use std::collections::HashMap;
+use std::{path::Path, process};
 
-fn main() {
-    let input = include_str!("input.txt");
+use aoc_core::input::{self, InputSource};
+use clap::Parser;
+use day1::{calibrations, lines_sum, Kind, Match, Matcher, Vocabulary};
 
-    println!("sum (simple): {}", lines_sum_simple(input));
-    println!("sum (complex): {}", lines_sum_complex(input));
-}
+#[derive(Debug, Parser)]
+#[command(about = "Day 1: Trebuchet?!")]
+struct Args {
+    /// Read the input from this file, or from stdin if `-`
+    input: Option<String>,
 
-fn lines_sum_simple(input: &str) -> u32 {
-    lines_sum(input, |line| {
-        let digits = line
-            .chars()
-            .filter_map(|c| c.to_digit(10))
-            .collect::<Vec<_>>();
+    /// The words read as digits in the complex sum: `english`, `dutch`,
+    /// `german`, or a file with a word and its digit on each line
+    #[arg(long, default_value = "english")]
+    vocabulary: String,
 
-        digits[0] * 10 + digits[digits.len() - 1]
-    })
+    /// Show the first and last digit found on each line, and where they are
+    #[arg(long)]
+    explain: bool,
 }
 
-fn lines_sum_complex(input: &str) -> u32 {
-    let digit_names = {
-        let mut hashmap = HashMap::new();
-        hashmap.insert("one", 1);
-        hashmap.insert("two", 2);
-        hashmap.insert("three", 3);
-        hashmap.insert("four", 4);
-        hashmap.insert("five", 5);
-        hashmap.insert("six", 6);
-        hashmap.insert("seven", 7);
-        hashmap.insert("eight", 8);
-        hashmap.insert("nine", 9);
-        hashmap
-    };
-
-    lines_sum(input, |line| {
-        let mut remaining_str = line;
-        let mut digits = Vec::new();
+fn main() {
+    let args = Args::parse();
 
-        'outer: while !remaining_str.is_empty() {
-            for (name, digit) in &digit_names {
-                if remaining_str.starts_with(name) {
-                    digits.push(*digit);
+    let vocabulary = Vocabulary::bundled(&args.vocabulary)
+        .map_or_else(|| Vocabulary::load(Path::new(&args.vocabulary)), Ok)
+        .unwrap_or_else(|e| {
+            eprintln!("error: {e}");
+            process::exit(1);
+        });
+    let source = args
+        .input
+        .map_or(InputSource::Default, |arg| InputSource::from_arg(&arg));
+    let input = input::read_or_exit(&source, 1);
 
-                    remaining_str = &remaining_str[1..];
-                    continue 'outer;
-                }
-            }
+    let mut failed = false;
+    for (name, matcher) in [
+        ("simple", Matcher::numerals()),
+        ("complex", Matcher::with_vocabulary(&vocabulary)),
+    ] {
+        if args.explain {
+            explain(&input, &matcher);
+        }
 
-            if let Some(digit) = remaining_str.chars().next().and_then(|c| c.to_digit(10)) {
-                digits.push(digit);
+        match lines_sum(&input, &matcher) {
+            Ok(sum) => println!("sum ({name}): {sum}"),
+            Err(e) => {
+                eprintln!("error: no {name} sum: {e:#}");
+                failed = true;
             }
-
-            remaining_str = &remaining_str[1..];
         }
+        if args.explain {
+            println!();
+        }
+    }
 
-        digits[0] * 10 + digits[digits.len() - 1]
-    })
-}
-
-fn lines_sum(input: &str, digits: impl Fn(&str) -> u32) -> u32 {
-    input.lines().map(digits).sum()
+    if failed {
+        process::exit(1);
+    }
 }
 
-#[test]
-fn day1_part1() {
-    assert_eq!(lines_sum_simple("1122"), 12);
-    assert_eq!(
-        lines_sum_simple(
-            "1abc2
-pqr3stu8vwx
-a1b2c3d4e5f
-treb7uchet"
-        ),
-        142
+fn explain(input: &str, matcher: &Matcher) {
+    println!(
+        "{:>4}  {:>5}  {:<28}  {:<28}",
+        "line", "value", "first", "last"
     );
+    for ((index, line), calibration) in input.lines().enumerate().zip(calibrations(input, matcher))
+    {
+        match calibration {
+            Ok(calibration) => println!(
+                "{:>4}  {:>5}  {:<28}  {:<28}",
+                index + 1,
+                calibration.value(),
+                describe(line, calibration.first),
+                describe(line, calibration.last)
+            ),
+            Err(e) => println!("{:>4}  {:>5}  {}", index + 1, "-", e.message()),
+        }
+    }
 }
 
-#[test]
-fn day1_part2() {
-    assert_eq!(
-        lines_sum_complex(
-            "two1nine
-eightwothree
-abcone2threexyz
-xtwone3four
-4nineeightseven2
-zoneight234
-7pqrstsixteen"
-        ),
-        281
+// e.g. `2 (word `two`, bytes 1..4)`
+fn describe(line: &str, digit: Match) -> String {
+    let source = match digit.kind {
+        Kind::Numeral => "numeral".to_string(),
+        Kind::Word => format!("word `{}`", &line[digit.start..digit.end]),
+    };
+
+    format!(
+        "{} ({source}, bytes {}..{})",
+        digit.value, digit.start, digit.end
     )
 }
@@ -0,0 +1,241 @@
+use std::collections::VecDeque;
+
+use crate::Vocabulary;
+
+const NUMERALS: [&str; 10] = ["0", "1", "2", "3", "4", "5", "6", "7", "8", "9"];
+
+// Marks a missing transition while the trie is being built.
+const NONE: usize = usize::MAX;
+
+// Finds every occurrence of a set of patterns in one pass over the text
+// (Aho-Corasick), including overlapping ones like the `eight` and `two` in
+// `eightwo`. The first match can be found from the start of the text and the
+// last one from its end, without looking at anything in between.
+#[derive(Debug, Clone)]
+pub struct Matcher {
+    forward: Automaton,
+    // Built from the reversed patterns, for reading the text backwards.
+    backward: Automaton,
+    longest: usize,
+}
+
+#[derive(Debug, Clone)]
+struct Automaton {
+    // The next state for each state and byte.
+    transitions: Vec<[usize; 256]>,
+    // The patterns that end in each state, as their length, value and kind.
+    outputs: Vec<Vec<(usize, u32, Kind)>>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Match {
+    // Byte offsets of the matched text.
+    pub start: usize,
+    pub end: usize,
+    pub value: u32,
+    pub kind: Kind,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Kind {
+    // A digit written as such, like `7`.
+    Numeral,
+    // A digit spelled out, like `seven`.
+    Word,
+}
+
+impl Automaton {
+    fn new(patterns: &[(Vec<u8>, u32, Kind)]) -> Self {
+        let mut transitions = vec![[NONE; 256]];
+        let mut outputs = vec![Vec::new()];
+        for (pattern, value, kind) in patterns {
+            let mut state = 0;
+            for &byte in pattern {
+                if transitions[state][byte as usize] == NONE {
+                    transitions[state][byte as usize] = transitions.len();
+                    transitions.push([NONE; 256]);
+                    outputs.push(Vec::new());
+                }
+                state = transitions[state][byte as usize];
+            }
+            outputs[state].push((pattern.len(), *value, *kind));
+        }
+
+        // Fill in the missing transitions breadth first. Each one goes where
+        // the longest suffix of the text so far that's also in the trie would
+        // go, and a state matches everything that suffix's state matches.
+        let mut suffix = vec![0; transitions.len()];
+        let mut queue = VecDeque::new();
+        for next in &mut transitions[0] {
+            if *next == NONE {
+                *next = 0;
+            } else {
+                queue.push_back(*next);
+            }
+        }
+        while let Some(state) = queue.pop_front() {
+            let inherited = outputs[suffix[state]].clone();
+            outputs[state].extend(inherited);
+
+            let fallbacks = transitions[suffix[state]];
+            for (next, fallback) in transitions[state].iter_mut().zip(fallbacks) {
+                match *next {
+                    NONE => *next = fallback,
+                    next => {
+                        suffix[next] = fallback;
+                        queue.push_back(next);
+                    }
+                }
+            }
+        }
+
+        Automaton {
+            transitions,
+            outputs,
+        }
+    }
+
+    // The matches ending after each byte, along with how many bytes have been
+    // read.
+    fn run<'a>(
+        &'a self,
+        bytes: impl Iterator<Item = u8> + 'a,
+    ) -> impl Iterator<Item = (usize, &'a [(usize, u32, Kind)])> + 'a {
+        bytes.enumerate().scan(0, move |state, (i, byte)| {
+            *state = self.transitions[*state][byte as usize];
+            Some((i + 1, self.outputs[*state].as_slice()))
+        })
+    }
+}
+
+impl Matcher {
+    pub fn new<S: AsRef<str>>(patterns: impl IntoIterator<Item = (S, u32)>) -> Self {
+        let patterns = patterns
+            .into_iter()
+            .map(|(pattern, value)| {
+                let pattern = pattern.as_ref().as_bytes().to_vec();
+                let kind = if pattern.iter().all(u8::is_ascii_digit) {
+                    Kind::Numeral
+                } else {
+                    Kind::Word
+                };
+                (pattern, value, kind)
+            })
+            .filter(|(pattern, _, _)| !pattern.is_empty())
+            .collect::<Vec<_>>();
+        let reversed = patterns
+            .iter()
+            .map(|(pattern, value, kind)| (pattern.iter().rev().copied().collect(), *value, *kind))
+            .collect::<Vec<_>>();
+
+        Matcher {
+            forward: Automaton::new(&patterns),
+            backward: Automaton::new(&reversed),
+            longest: patterns
+                .iter()
+                .map(|(pattern, _, _)| pattern.len())
+                .max()
+                .unwrap_or(0),
+        }
+    }
+
+    // Matches the digits `0` to `9`.
+    pub fn numerals() -> Self {
+        Matcher::new(NUMERALS.into_iter().zip(0..))
+    }
+
+    // Matches the digits as well as the words in `vocabulary`.
+    pub fn with_vocabulary(vocabulary: &Vocabulary) -> Self {
+        Matcher::new(NUMERALS.into_iter().zip(0..).chain(vocabulary.words()))
+    }
+
+    // Every match in `text`, ordered by where they end.
+    pub fn find_iter<'a>(&'a self, text: &'a str) -> impl Iterator<Item = Match> + 'a {
+        self.forward.run(text.bytes()).flat_map(|(end, outputs)| {
+            outputs.iter().map(move |&(len, value, kind)| Match {
+                start: end - len,
+                end,
+                value,
+                kind,
+            })
+        })
+    }
+
+    // The match that starts first in `text`.
+    pub fn first(&self, text: &str) -> Option<Match> {
+        // Matches are found by where they end, so a longer one that starts
+        // earlier may still turn up until we're past the longest pattern.
+        let mut first: Option<Match> = None;
+        for (end, outputs) in self.forward.run(text.bytes()) {
+            if first.is_some_and(|first| end >= first.start + self.longest) {
+                break;
+            }
+
+            for &(len, value, kind) in outputs {
+                if first.is_none_or(|first| end - len < first.start) {
+                    first = Some(Match {
+                        start: end - len,
+                        end,
+                        value,
+                        kind,
+                    });
+                }
+            }
+        }
+
+        first
+    }
+
+    // The match that starts last in `text`. Reading backwards, matches are
+    // found by where they start, so the first one found is it.
+    pub fn last(&self, text: &str) -> Option<Match> {
+        let (read, outputs) = self
+            .backward
+            .run(text.bytes().rev())
+            .find(|(_, outputs)| !outputs.is_empty())?;
+        let (len, value, kind) = outputs[0];
+        let start = text.len() - read;
+
+        Some(Match {
+            start,
+            end: start + len,
+            value,
+            kind,
+        })
+    }
+}
+
+#[test]
+fn matcher_overlapping() {
+    let matcher = Matcher::new([("he", 1), ("she", 2), ("hers", 3), ("his", 4)]);
+    let matches = matcher
+        .find_iter("ushers")
+        .map(|m| (m.start, m.end, m.value))
+        .collect::<Vec<_>>();
+    assert_eq!(matches, [(1, 4, 2), (2, 4, 1), (2, 6, 3)]);
+
+    let values = Matcher::with_vocabulary(&Vocabulary::english())
+        .find_iter("eightwone7")
+        .map(|m| m.value)
+        .collect::<Vec<_>>();
+    assert_eq!(values, [8, 2, 1, 7]);
+}
+
+#[test]
+fn matcher_first_last() {
+    // `ab` is found first, but `xaby` starts before it.
+    let matcher = Matcher::new([("ab", 1), ("xaby", 2)]);
+    assert_eq!(
+        matcher.first("zxaby").map(|m| (m.start, m.value)),
+        Some((1, 2))
+    );
+    assert_eq!(
+        matcher.last("zxaby").map(|m| (m.start, m.value)),
+        Some((2, 1))
+    );
+    assert_eq!(matcher.first("zzz"), None);
+
+    let matcher = Matcher::with_vocabulary(&Vocabulary::english());
+    let last = matcher.last("4nineeightseven2zoneight").unwrap();
+    assert_eq!((last.start, last.end, last.value), (19, 24, 8));
+}
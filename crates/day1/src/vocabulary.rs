@@ -0,0 +1,152 @@
+use std::{fs, path::Path};
+
+use aoc_core::{
+    text::{number, words},
+    Error,
+};
+
+// The names of the digits one to nine in each language that comes built in.
+const BUNDLED: [(&str, [&str; 9]); 3] = [
+    (
+        "english",
+        [
+            "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+        ],
+    ),
+    (
+        "dutch",
+        [
+            "een", "twee", "drie", "vier", "vijf", "zes", "zeven", "acht", "negen",
+        ],
+    ),
+    (
+        "german",
+        [
+            "eins", "zwei", "drei", "vier", "fünf", "sechs", "sieben", "acht", "neun",
+        ],
+    ),
+];
+
+// The words that are read as digits, next to the numerals themselves.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Vocabulary {
+    words: Vec<(String, u32)>,
+}
+
+impl Vocabulary {
+    // Each word's value should be a single digit.
+    pub fn new<S: Into<String>>(words: impl IntoIterator<Item = (S, u32)>) -> Self {
+        Vocabulary {
+            words: words
+                .into_iter()
+                .map(|(word, value)| (word.into(), value))
+                .collect(),
+        }
+    }
+
+    pub fn english() -> Self {
+        Vocabulary::bundled("english").unwrap()
+    }
+
+    // One of the built in vocabularies: `english`, `dutch` or `german`.
+    pub fn bundled(name: &str) -> Option<Self> {
+        let (_, names) = BUNDLED.iter().find(|(bundled, _)| *bundled == name)?;
+
+        Some(Vocabulary::new(names.iter().copied().zip(1..)))
+    }
+
+    // A vocabulary with a word and its digit on each line, e.g. `een 1`.
+    // Empty lines and lines starting with `#` are ignored.
+    pub fn parse(contents: &str) -> Result<Self, Error> {
+        let mut vocabulary = Vec::new();
+        let mut errors = Vec::new();
+        for (index, line) in contents.lines().enumerate() {
+            if line.trim().is_empty() || line.trim_start().starts_with('#') {
+                continue;
+            }
+
+            let entry = match words(line).collect::<Vec<_>>()[..] {
+                [(_, word), (offset, digit)] => {
+                    number::<u32>(index, line, offset..offset + digit.len()).and_then(|value| {
+                        match value {
+                            0..=9 => Ok((word.to_string(), value)),
+                            _ => Err(Error::in_line(
+                                index,
+                                line,
+                                offset..offset + digit.len(),
+                                "expected a single digit",
+                            )),
+                        }
+                    })
+                }
+                _ => Err(Error::in_line(
+                    index,
+                    line,
+                    0..line.len(),
+                    "expected a word and its digit, e.g. `one 1`",
+                )),
+            };
+            match entry {
+                Ok(entry) => vocabulary.push(entry),
+                Err(e) => errors.push(e),
+            }
+        }
+
+        match Error::combine(errors) {
+            Some(e) => Err(e),
+            None => Ok(Vocabulary::new(vocabulary)),
+        }
+    }
+
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let contents = fs::read_to_string(path)
+            .map_err(|e| format!("failed to read `{}`: {e}", path.display()))?;
+
+        Vocabulary::parse(&contents)
+            .map_err(|e| format!("invalid vocabulary `{}`: {e:#}", path.display()))
+    }
+
+    pub fn words(&self) -> impl Iterator<Item = (&str, u32)> {
+        self.words
+            .iter()
+            .map(|(word, value)| (word.as_str(), *value))
+    }
+}
+
+impl Default for Vocabulary {
+    fn default() -> Self {
+        Vocabulary::english()
+    }
+}
+
+#[test]
+fn vocabulary_parse() {
+    let vocabulary = Vocabulary::parse("# Dutch\neen 1\n\ntwee 2\n").unwrap();
+    assert_eq!(vocabulary, Vocabulary::new([("een", 1), ("twee", 2)]));
+
+    let error = Vocabulary::parse("een 1\ntwee\ndrie 33\n").unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "line 2, column 1: expected a word and its digit, e.g. `one 1` (and 1 more error)"
+    );
+    assert_eq!(
+        error.others()[0].to_string(),
+        "line 3, column 6: expected a single digit"
+    );
+}
+
+#[test]
+fn vocabulary_bundled_overlaps() {
+    use crate::Matcher;
+
+    let values = |name: &str, line: &str| {
+        let matcher = Matcher::with_vocabulary(&Vocabulary::bundled(name).unwrap());
+        matcher.find_iter(line).map(|m| m.value).collect::<Vec<_>>()
+    };
+
+    assert_eq!(values("english", "eightwoneight"), [8, 2, 1, 8]);
+    assert_eq!(values("dutch", "achtweeenegen"), [8, 2, 1, 9]);
+    assert_eq!(values("german", "dreinsechsieben"), [3, 1, 6, 7]);
+    assert_eq!(values("german", "fünf5"), [5, 5]);
+    assert_eq!(Vocabulary::bundled("klingon"), None);
+}
@@ -0,0 +1,286 @@
+use std::collections::HashSet;
+
+use aoc_core::{Error, Solution};
+use aoc_grid::{Direction, Position};
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u8 = 10;
+
+    type Input = Grid;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        Grid::parse(input)
+    }
+
+    fn part1(grid: &Self::Input) -> usize {
+        grid.find_loop_furthest_point()
+    }
+
+    fn part2(grid: &Self::Input) -> usize {
+        grid.find_num_cells_enclosed_in_loop()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Cell {
+    NorthSouth, // |
+    EastWest,   // -
+    NorthEast,  // L
+    NorthWest,  // J
+    SouthWest,  // 7
+    SouthEast,  // F
+    Empty,      // .
+    Start,      // S
+}
+
+impl Cell {
+    fn is_start(&self) -> bool {
+        matches!(self, Self::Start)
+    }
+
+    fn connects_south(&self) -> bool {
+        matches!(
+            self,
+            Self::NorthSouth | Self::SouthWest | Self::SouthEast | Self::Start
+        )
+    }
+
+    fn connects_north(&self) -> bool {
+        matches!(
+            self,
+            Self::NorthSouth | Self::NorthEast | Self::NorthWest | Self::Start
+        )
+    }
+
+    fn connects_east(&self) -> bool {
+        matches!(
+            self,
+            Self::EastWest | Self::NorthEast | Self::SouthEast | Self::Start
+        )
+    }
+
+    fn connects_west(&self) -> bool {
+        matches!(
+            self,
+            Self::EastWest | Self::NorthWest | Self::SouthWest | Self::Start
+        )
+    }
+
+    fn next_direction_from(&self, previous_direction: Direction) -> Direction {
+        match (self, previous_direction) {
+            (Self::NorthSouth, Direction::North) => Direction::South,
+            (Self::NorthSouth, Direction::South) => Direction::North,
+            (Self::EastWest, Direction::East) => Direction::West,
+            (Self::EastWest, Direction::West) => Direction::East,
+            (Self::NorthEast, Direction::North) => Direction::East,
+            (Self::NorthEast, Direction::East) => Direction::North,
+            (Self::NorthWest, Direction::North) => Direction::West,
+            (Self::NorthWest, Direction::West) => Direction::North,
+            (Self::SouthWest, Direction::South) => Direction::West,
+            (Self::SouthWest, Direction::West) => Direction::South,
+            (Self::SouthEast, Direction::South) => Direction::East,
+            (Self::SouthEast, Direction::East) => Direction::South,
+            _ => panic!("Invalid direction ({:?} -> {:?})", self, previous_direction),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Grid {
+    cells: aoc_grid::Grid<Cell>,
+    start: Position,
+}
+
+impl Grid {
+    pub fn parse(input: &str) -> Result<Self, Error> {
+        let cells = aoc_grid::Grid::parse(input, |c| match c {
+            '|' => Ok(Cell::NorthSouth),
+            '-' => Ok(Cell::EastWest),
+            'L' => Ok(Cell::NorthEast),
+            'J' => Ok(Cell::NorthWest),
+            '7' => Ok(Cell::SouthWest),
+            'F' => Ok(Cell::SouthEast),
+            '.' => Ok(Cell::Empty),
+            'S' => Ok(Cell::Start),
+            _ => Err(format!(
+                "unexpected character `{c}`, expected a pipe, `.` or `S`"
+            )),
+        })?;
+
+        let starts = cells
+            .iter()
+            .filter(|(_, cell)| cell.is_start())
+            .map(|(position, _)| position)
+            .collect::<Vec<_>>();
+        let start = match starts.as_slice() {
+            [] => return Err(Error::new("there is no starting position `S`")),
+            [start] => *start,
+            [_, position, ..] => {
+                let line = input.lines().nth(position.y).unwrap_or_default();
+                return Err(Error::in_line(
+                    position.y,
+                    line,
+                    position.x..position.x + 1,
+                    "there is more than one starting position `S`",
+                ));
+            }
+        };
+
+        Ok(Self { cells, start })
+    }
+
+    pub fn find_loop_furthest_point(&self) -> usize {
+        self.find_loop().len().div_ceil(2)
+    }
+
+    pub fn find_num_cells_enclosed_in_loop(&self) -> usize {
+        let loop_ = self.find_loop();
+        let mut num_enclosed = 0;
+        let mut inside_loop = false;
+        for (position, cell) in self.cells.iter() {
+            if loop_.contains(&position) {
+                // Flip inside loop if crossing a vertical line.
+                match cell {
+                    Cell::NorthSouth | Cell::NorthEast | Cell::NorthWest => {
+                        inside_loop = !inside_loop;
+                    }
+                    _ => {}
+                }
+            } else if inside_loop {
+                num_enclosed += 1;
+            }
+        }
+
+        num_enclosed
+    }
+
+    fn find_loop(&self) -> HashSet<Position> {
+        // We know where we start, but not in which direction we need to go.
+        // We can try all four directions and see which one leads us back to the
+        // start, checking if the tiles connect with us.
+        for direction in Direction::ALL {
+            // Find the cell in that direction.
+            let Some(connecting_position) = self.cells.step(self.start, direction) else {
+                continue;
+            };
+
+            // Ensure we can go in that direction (the cell has a pipe that connects this way).
+            if !self.connects(connecting_position, direction) {
+                continue;
+            }
+
+            // Find the loop from that cell.
+            if let Some(loop_) = self.find_loop_from(connecting_position, direction.opposite()) {
+                return loop_;
+            }
+        }
+
+        panic!("No loop found");
+    }
+
+    fn find_loop_from(
+        &self,
+        start: Position,
+        mut previous_direction: Direction,
+    ) -> Option<HashSet<Position>> {
+        let mut current_position = start;
+        let mut loop_ = HashSet::new();
+        loop_.insert(current_position);
+
+        loop {
+            // What direction are we going?
+            let direction = self.cells[current_position].next_direction_from(previous_direction);
+
+            // Find the cell in that direction.
+            let connecting_position = self.cells.step(current_position, direction)?;
+
+            // Ensure we can go in that direction (the cell has a pipe that connects this way).
+            if !self.connects(connecting_position, direction) {
+                return None;
+            }
+
+            // Add the cell to the loop.
+            loop_.insert(connecting_position);
+
+            // Check if we've reached the start again.
+            if self.cells[connecting_position].is_start() {
+                return Some(loop_);
+            }
+
+            // Continue in the same direction.
+            current_position = connecting_position;
+            previous_direction = direction.opposite();
+        }
+    }
+
+    // Whether the pipe at `position` connects back to where we came from, when
+    // entering it going in `direction`.
+    fn connects(&self, position: Position, direction: Direction) -> bool {
+        let cell = self.cells[position];
+        match direction {
+            Direction::North => cell.connects_south(),
+            Direction::East => cell.connects_west(),
+            Direction::South => cell.connects_north(),
+            Direction::West => cell.connects_east(),
+        }
+    }
+}
+
+#[test]
+fn day10_part1() {
+    let grid = Grid::parse(
+        "..F7.
+.FJ|.
+SJ.L7
+|F--J
+LJ...",
+    )
+    .unwrap();
+    assert_eq!(grid.find_loop_furthest_point(), 8);
+}
+
+#[test]
+fn day10_part2() {
+    let grid = Grid::parse(
+        "...........
+.S-------7.
+.|F-----7|.
+.||.....||.
+.||.....||.
+.|L-7.F-J|.
+.|..|.|..|.
+.L--J.L--J.
+...........",
+    )
+    .unwrap();
+    assert_eq!(grid.find_num_cells_enclosed_in_loop(), 4);
+
+    let grid = Grid::parse(
+        "FF7FSF7F7F7F7F7F---7
+L|LJ||||||||||||F--J
+FL-7LJLJ||||||LJL-77
+F--JF--7||LJLJ7F7FJ-
+L---JF-JLJ.||-FJLJJ7
+|F|F-JF---7F7-L7L|7|
+|FFJF7L7F-JF7|JL---7
+7-L-JL7||F7|L7F-7F7|
+L.L7LFJ|||||FJL7||LJ
+L7JLJL-JLJLJL--JLJ.L",
+    )
+    .unwrap();
+    assert_eq!(grid.find_num_cells_enclosed_in_loop(), 10);
+}
+
+#[test]
+fn day10_errors() {
+    assert_eq!(
+        Day10::parse(".....\n.S-7.\n.|.|.\n.L-X.\n.....")
+            .unwrap_err()
+            .to_string(),
+        "line 4, column 4: unexpected character `X`, expected a pipe, `.` or `S`"
+    );
+}
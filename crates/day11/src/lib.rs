@@ -0,0 +1,133 @@
+use aoc_core::{Error, Solution};
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const DAY: u8 = 11;
+
+    type Input = Vec<Galaxy>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        parse(input)
+    }
+
+    fn part1(galaxies: &Self::Input) -> usize {
+        shortest_distance_pairs_sum(&expand(galaxies, 2))
+    }
+
+    fn part2(galaxies: &Self::Input) -> usize {
+        shortest_distance_pairs_sum(&expand(galaxies, 1000000))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Galaxy {
+    position: (usize, usize),
+}
+
+impl Galaxy {
+    fn distance(&self, other: &Galaxy) -> usize {
+        ((self.position.0 as isize - other.position.0 as isize).abs() - 1
+            + (self.position.1 as isize - other.position.1 as isize).abs()
+            + 1) as usize
+    }
+}
+
+pub fn parse(input: &str) -> Result<Vec<Galaxy>, Error> {
+    let mut galaxies = Vec::new();
+
+    for (y, line) in input.lines().enumerate() {
+        for (x, (offset, c)) in line.char_indices().enumerate() {
+            match c {
+                '#' => galaxies.push(Galaxy { position: (x, y) }),
+                '.' => {}
+                _ => {
+                    return Err(Error::in_line(
+                        y,
+                        line,
+                        offset..offset + c.len_utf8(),
+                        format!("unexpected character `{c}`, expected `#` or `.`"),
+                    ))
+                }
+            }
+        }
+    }
+
+    Ok(galaxies)
+}
+
+pub fn expand(galaxies: &[Galaxy], expansion_ratio: usize) -> Vec<Galaxy> {
+    let columns_with_galaxies = galaxies
+        .iter()
+        .map(|galaxy| galaxy.position.0)
+        .collect::<Vec<_>>();
+    let rows_with_galaxies = galaxies
+        .iter()
+        .map(|galaxy| galaxy.position.1)
+        .collect::<Vec<_>>();
+
+    let mut galaxies = galaxies.to_vec();
+    for galaxy in &mut galaxies {
+        galaxy.position.0 += (0..galaxy.position.0)
+            .filter(|x| !columns_with_galaxies.contains(x))
+            .count()
+            * (expansion_ratio - 1);
+        galaxy.position.1 += (0..galaxy.position.1)
+            .filter(|y| !rows_with_galaxies.contains(y))
+            .count()
+            * (expansion_ratio - 1);
+    }
+
+    galaxies
+}
+
+pub fn shortest_distance_pairs_sum(galaxies: &[Galaxy]) -> usize {
+    let mut sum = 0;
+    for (i, galaxy) in galaxies.iter().enumerate() {
+        for other_galaxy in &galaxies[i + 1..] {
+            sum += galaxy.distance(other_galaxy);
+        }
+    }
+    sum
+}
+
+#[cfg(test)]
+const TEST_INPUT: &str = "...#......
+.......#..
+#.........
+..........
+......#...
+.#........
+.........#
+..........
+.......#..
+#...#.....";
+
+#[test]
+fn day11_part1() {
+    let galaxies = expand(&parse(TEST_INPUT).unwrap(), 2);
+    assert_eq!(galaxies.len(), 9);
+    assert_eq!(galaxies[0].position, (4, 0));
+    assert_eq!(galaxies[0].distance(&galaxies[6]), 15);
+
+    assert_eq!(shortest_distance_pairs_sum(&galaxies), 374);
+}
+
+#[test]
+fn day11_part2() {
+    let galaxies = expand(&parse(TEST_INPUT).unwrap(), 10);
+    assert_eq!(shortest_distance_pairs_sum(&galaxies), 1030);
+
+    let galaxies = expand(&parse(TEST_INPUT).unwrap(), 100);
+    assert_eq!(shortest_distance_pairs_sum(&galaxies), 8410);
+}
+
+#[test]
+fn day11_errors() {
+    assert_eq!(
+        Day11::parse("#..\n.x#").unwrap_err().to_string(),
+        "line 2, column 2: unexpected character `x`, expected `#` or `.`"
+    );
+}
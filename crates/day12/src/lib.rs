@@ -0,0 +1,267 @@
+use std::collections::HashMap;
+
+use aoc_core::{text::number, Error, Solution};
+use rayon::prelude::*;
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    const DAY: u8 = 12;
+
+    type Input = Vec<Record>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        parse(input)
+    }
+
+    fn part1(records: &Self::Input) -> usize {
+        records
+            .iter()
+            .map(|record| record.get_possible_states_count())
+            .sum()
+    }
+
+    fn part2(records: &Self::Input) -> usize {
+        let mut records = records.clone();
+        for record in records.iter_mut() {
+            record.multiply(5);
+        }
+
+        records
+            .par_iter()
+            .map(|record| record.get_possible_states_count())
+            .sum()
+    }
+}
+
+pub fn parse(input: &str) -> Result<Vec<Record>, Error> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(index, line)| Record::parse(index, line))
+        .collect()
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ParsedState {
+    Operational,
+    Damaged,
+    Unknown,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Record {
+    states: Vec<ParsedState>,
+    contigious_damaged_counts: Vec<usize>,
+}
+
+impl Record {
+    // Parse the line at `index` (counting from 0) in the input.
+    fn parse(index: usize, line: &str) -> Result<Self, Error> {
+        let Some((states, counts)) = line.split_once(' ') else {
+            return Err(Error::in_line(
+                index,
+                line,
+                line.len()..line.len(),
+                "expected the states followed by a space and the damaged group sizes",
+            ));
+        };
+
+        let states = states
+            .char_indices()
+            .map(|(offset, c)| match c {
+                '.' => Ok(ParsedState::Operational),
+                '#' => Ok(ParsedState::Damaged),
+                '?' => Ok(ParsedState::Unknown),
+                _ => Err(Error::in_line(
+                    index,
+                    line,
+                    offset..offset + c.len_utf8(),
+                    format!("unexpected state `{c}`, expected `.`, `#` or `?`"),
+                )),
+            })
+            .collect::<Result<_, _>>()?;
+
+        let mut offset = line.len() - counts.len();
+        let mut contigious_damaged_counts = Vec::new();
+        for count in counts.split(',') {
+            contigious_damaged_counts.push(number(index, line, offset..offset + count.len())?);
+            offset += count.len() + 1;
+        }
+
+        Ok(Self {
+            states,
+            contigious_damaged_counts,
+        })
+    }
+
+    pub fn multiply(&mut self, n: usize) {
+        self.states = (0..n).fold(vec![], |mut acc, i| {
+            acc.extend(self.states.iter().cloned());
+            if i < n - 1 {
+                acc.push(ParsedState::Unknown);
+            }
+            acc
+        });
+        self.contigious_damaged_counts = self
+            .contigious_damaged_counts
+            .iter()
+            .cycle()
+            .take(self.contigious_damaged_counts.len() * n)
+            .copied()
+            .collect();
+    }
+
+    pub fn get_possible_states_count(&self) -> usize {
+        fn get_possible_states_count_2_inner(
+            cached_results: &mut HashMap<(Vec<ParsedState>, Vec<usize>, bool), usize>,
+            states: &[ParsedState],
+            contigious_damaged_counts: &[usize],
+            previous_was_damaged: bool,
+            damaged_groups: &[usize],
+        ) -> usize {
+            let key = (
+                states.to_vec(),
+                damaged_groups.to_vec(),
+                previous_was_damaged,
+            );
+            if let Some(result) = cached_results.get(&key) {
+                return *result;
+            }
+
+            let mut result = states.is_empty().then(|| {
+                if damaged_groups == contigious_damaged_counts {
+                    1
+                } else {
+                    0
+                }
+            });
+
+            result = result.or_else(|| {
+                if damaged_groups.len() > contigious_damaged_counts.len()
+                    || (!previous_was_damaged
+                        && damaged_groups != &contigious_damaged_counts[..damaged_groups.len()])
+                {
+                    Some(0)
+                } else {
+                    None
+                }
+            });
+
+            let result = result.unwrap_or_else(|| {
+                let mut result = 0;
+                if matches!(states[0], ParsedState::Operational | ParsedState::Unknown) {
+                    result += get_possible_states_count_2_inner(
+                        cached_results,
+                        &states[1..],
+                        contigious_damaged_counts,
+                        false,
+                        damaged_groups,
+                    );
+                }
+
+                // No else if, allow unknown to enter both clauses
+                if matches!(states[0], ParsedState::Damaged | ParsedState::Unknown) {
+                    let mut damaged_groups = damaged_groups.to_vec();
+
+                    result += if previous_was_damaged {
+                        let index = damaged_groups.len() - 1;
+                        damaged_groups[index] += 1;
+                        get_possible_states_count_2_inner(
+                            cached_results,
+                            &states[1..],
+                            contigious_damaged_counts,
+                            true,
+                            &damaged_groups,
+                        )
+                    } else {
+                        damaged_groups.push(1);
+                        get_possible_states_count_2_inner(
+                            cached_results,
+                            &states[1..],
+                            contigious_damaged_counts,
+                            true,
+                            &damaged_groups,
+                        )
+                    };
+                }
+
+                result
+            });
+
+            cached_results.insert(key, result);
+            result
+        }
+
+        get_possible_states_count_2_inner(
+            &mut HashMap::new(),
+            &self.states,
+            &self.contigious_damaged_counts,
+            false,
+            &[],
+        )
+    }
+}
+
+#[test]
+fn day12_part1() {
+    let records = parse(
+        "???.### 1,1,3
+.??..??...?##. 1,1,3
+?#?#?#?#?#?#?#? 1,3,1,6
+????.#...#... 4,1,1
+????.######..#####. 1,6,5
+?###???????? 3,2,1",
+    )
+    .unwrap();
+    assert_eq!(records.len(), 6);
+    assert_eq!(records[0].get_possible_states_count(), 1);
+    assert_eq!(records[1].get_possible_states_count(), 4);
+    assert_eq!(records[2].get_possible_states_count(), 1);
+    assert_eq!(records[3].get_possible_states_count(), 1);
+    assert_eq!(records[4].get_possible_states_count(), 4);
+    assert_eq!(records[5].get_possible_states_count(), 10);
+}
+
+#[test]
+fn day12_part2() {
+    let mut records = parse(
+        "???.### 1,1,3
+.??..??...?##. 1,1,3
+?#?#?#?#?#?#?#? 1,3,1,6
+????.#...#... 4,1,1
+????.######..#####. 1,6,5
+?###???????? 3,2,1",
+    )
+    .unwrap();
+    for record in records.iter_mut() {
+        record.multiply(5);
+    }
+
+    assert_eq!(records[0].get_possible_states_count(), 1);
+    assert_eq!(records[1].get_possible_states_count(), 16384);
+    assert_eq!(records[2].get_possible_states_count(), 1);
+    assert_eq!(records[3].get_possible_states_count(), 16);
+    assert_eq!(records[4].get_possible_states_count(), 2500);
+    assert_eq!(records[5].get_possible_states_count(), 506250);
+}
+
+#[test]
+fn day12_errors() {
+    let error = |input| Day12::parse(input).unwrap_err().to_string();
+
+    assert_eq!(
+        error("???.### 1,1,3\n.??..??...?##."),
+        "line 2, column 15: expected the states followed by a space and the damaged group sizes"
+    );
+    assert_eq!(
+        error("???.### 1,1,3\n?x# 1"),
+        "line 2, column 2: unexpected state `x`, expected `.`, `#` or `?`"
+    );
+    assert_eq!(
+        error("???.### 1,1,3\n??# 1,x"),
+        "line 2, column 7: invalid number `x`: invalid digit found in string"
+    );
+}
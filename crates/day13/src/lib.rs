@@ -0,0 +1,209 @@
+use aoc_core::{Error, Solution};
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    const DAY: u8 = 13;
+
+    type Input = Vec<Grid>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        parse(input)
+    }
+
+    fn part1(grids: &Self::Input) -> usize {
+        part1(grids)
+    }
+
+    fn part2(grids: &Self::Input) -> usize {
+        part2(grids)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid {
+    cells: aoc_grid::Grid<bool>,
+}
+
+impl Grid {
+    fn get_reflection_position(&self, allowed_difference_count: usize) -> Reflection {
+        // Mirroring the columns is the same as mirroring the rows of the
+        // transposed grid.
+        if let Some(before_column) =
+            find_mirrored_row(&self.cells.transpose(), allowed_difference_count)
+        {
+            return Reflection::Vertical { before_column };
+        }
+
+        if let Some(before_row) = find_mirrored_row(&self.cells, allowed_difference_count) {
+            return Reflection::Horizontal { before_row };
+        }
+
+        panic!("No reflection found: {:#?}", self);
+    }
+}
+
+// Find the row before which the grid is mirrored, where exactly
+// `allowed_difference_count` cells differ from their reflection.
+fn find_mirrored_row(
+    cells: &aoc_grid::Grid<bool>,
+    allowed_difference_count: usize,
+) -> Option<usize> {
+    (1..cells.height()).find(|&y| {
+        // Check if all rows above are the same as the ones below
+        let num_different = (0..y)
+            .rev()
+            .zip(y..cells.height())
+            .map(|(top, bottom)| {
+                cells
+                    .row(top)
+                    .iter()
+                    .zip(cells.row(bottom))
+                    .filter(|(top, bottom)| top != bottom)
+                    .count()
+            })
+            .sum::<usize>();
+
+        num_different == allowed_difference_count
+    })
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+enum Reflection {
+    Vertical { before_column: usize },
+    Horizontal { before_row: usize },
+}
+
+pub fn parse(input: &str) -> Result<Vec<Grid>, Error> {
+    let mut grids = Vec::new();
+    let mut cells = Vec::new();
+    let mut width = None;
+
+    // The grids are separated by empty lines, so add one at the end to finish
+    // the last grid.
+    for (index, line) in input.lines().chain([""]).enumerate() {
+        if line.is_empty() {
+            if let Some(width) = width.take() {
+                grids.push(Grid {
+                    cells: aoc_grid::Grid::new(width, std::mem::take(&mut cells)),
+                });
+            }
+            continue;
+        }
+
+        let line_width = line.chars().count();
+        if let Some(width) = width.filter(|&width| width != line_width) {
+            return Err(Error::in_line(
+                index,
+                line,
+                0..line.len(),
+                format!("row is {line_width} characters wide, expected {width}"),
+            ));
+        }
+        width = Some(line_width);
+
+        for (offset, c) in line.char_indices() {
+            cells.push(match c {
+                '#' => true,
+                '.' => false,
+                _ => {
+                    return Err(Error::in_line(
+                        index,
+                        line,
+                        offset..offset + c.len_utf8(),
+                        format!("unexpected character `{c}`, expected `#` or `.`"),
+                    ))
+                }
+            });
+        }
+    }
+
+    Ok(grids)
+}
+
+pub fn part1(grids: &[Grid]) -> usize {
+    grids
+        .iter()
+        .map(|grid| grid.get_reflection_position(0))
+        .map(|reflection| match reflection {
+            Reflection::Vertical { before_column } => before_column,
+            Reflection::Horizontal { before_row } => before_row * 100,
+        })
+        .sum()
+}
+
+pub fn part2(grids: &[Grid]) -> usize {
+    grids
+        .iter()
+        .map(|grid| grid.get_reflection_position(1))
+        .map(|reflection| match reflection {
+            Reflection::Vertical { before_column } => before_column,
+            Reflection::Horizontal { before_row } => before_row * 100,
+        })
+        .sum()
+}
+
+#[cfg(test)]
+const TEST_INPUT: &str = "#.##..##.
+..#.##.#.
+##......#
+##......#
+..#.##.#.
+..##..##.
+#.#.##.#.
+
+#...##..#
+#....#..#
+..##..###
+#####.##.
+#####.##.
+..##..###
+#....#..#";
+
+#[test]
+fn day13_part1() {
+    let grids = parse(TEST_INPUT).unwrap();
+
+    assert_eq!(grids.len(), 2);
+    assert_eq!(
+        grids[0].get_reflection_position(0),
+        Reflection::Vertical { before_column: 5 }
+    );
+    assert_eq!(
+        grids[1].get_reflection_position(0),
+        Reflection::Horizontal { before_row: 4 }
+    );
+    assert_eq!(part1(&grids), 405);
+}
+
+#[test]
+fn day13_part2() {
+    let grids = parse(TEST_INPUT).unwrap();
+
+    assert_eq!(grids.len(), 2);
+    assert_eq!(
+        grids[0].get_reflection_position(1),
+        Reflection::Horizontal { before_row: 3 }
+    );
+    assert_eq!(
+        grids[1].get_reflection_position(1),
+        Reflection::Horizontal { before_row: 1 }
+    );
+    assert_eq!(part2(&grids), 400);
+}
+
+#[test]
+fn day13_errors() {
+    let error = |input| Day13::parse(input).unwrap_err().to_string();
+
+    assert_eq!(
+        error("#.#\n.x#"),
+        "line 2, column 2: unexpected character `x`, expected `#` or `.`"
+    );
+    assert_eq!(
+        error("#.#\n.#"),
+        "line 2, column 1: row is 2 characters wide, expected 3"
+    );
+}
@@ -0,0 +1,166 @@
+use std::collections::HashMap;
+
+use aoc_core::{Error, Solution};
+use aoc_grid::Direction;
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    const DAY: u8 = 14;
+
+    type Input = Grid;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        parse(input)
+    }
+
+    fn part1(grid: &Self::Input) -> usize {
+        let mut grid = grid.clone();
+        grid.roll_round_stones_north();
+
+        grid.total_load()
+    }
+
+    fn part2(grid: &Self::Input) -> usize {
+        let mut grid = grid.clone();
+        grid.spin_cycle(1000000000);
+
+        grid.total_load()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Cell {
+    Empty,
+    RoundStone,
+    SquareStone,
+}
+
+#[derive(Debug, Clone)]
+pub struct Grid {
+    cells: aoc_grid::Grid<Cell>,
+}
+
+impl Grid {
+    pub fn roll_round_stones_north(&mut self) {
+        self.roll_round_stones(Direction::North);
+    }
+
+    fn roll_round_stones(&mut self, direction: Direction) {
+        // Move the stones closest to the edge we're rolling towards first, so
+        // they're out of the way of the stones behind them.
+        let positions = match direction {
+            Direction::North | Direction::West => self.cells.positions().collect::<Vec<_>>(),
+            Direction::South | Direction::East => self.cells.positions().rev().collect(),
+        };
+
+        for position in positions {
+            if self.cells[position] != Cell::RoundStone {
+                continue;
+            }
+
+            let mut target_position = position;
+            while let Some(next_position) = self
+                .cells
+                .step(target_position, direction)
+                .filter(|next_position| self.cells[*next_position] == Cell::Empty)
+            {
+                target_position = next_position;
+            }
+
+            self.cells.swap(position, target_position);
+        }
+    }
+
+    fn roll_round_stones_cycle(&mut self) {
+        self.roll_round_stones(Direction::North);
+        self.roll_round_stones(Direction::West);
+        self.roll_round_stones(Direction::South);
+        self.roll_round_stones(Direction::East);
+    }
+
+    pub fn spin_cycle(&mut self, times: usize) {
+        let mut cache = HashMap::new();
+        for i in 0..times {
+            if let Some(previous_i) = cache.insert(self.cells.clone(), i) {
+                let cycle = i - previous_i;
+                let remaining = (times - i) % cycle;
+                for _ in 0..remaining {
+                    self.roll_round_stones_cycle();
+                }
+
+                break;
+            }
+
+            self.roll_round_stones_cycle();
+        }
+    }
+
+    pub fn total_load(&self) -> usize {
+        let height = self.cells.height();
+
+        self.cells
+            .iter()
+            .map(|(position, cell)| {
+                let rock_weight = height - position.y;
+
+                match cell {
+                    Cell::RoundStone => rock_weight,
+                    Cell::SquareStone => 0,
+                    Cell::Empty => 0,
+                }
+            })
+            .sum()
+    }
+}
+
+pub fn parse(input: &str) -> Result<Grid, Error> {
+    let cells = aoc_grid::Grid::parse(input, |c| match c {
+        '.' => Ok(Cell::Empty),
+        'O' => Ok(Cell::RoundStone),
+        '#' => Ok(Cell::SquareStone),
+        _ => Err(format!(
+            "unexpected character `{c}`, expected `.`, `O` or `#`"
+        )),
+    })?;
+
+    Ok(Grid { cells })
+}
+
+#[cfg(test)]
+const TEST_INPUT: &str = "O....#....
+O.OO#....#
+.....##...
+OO.#O....O
+.O.....O#.
+O.#..O.#.#
+..O..#O..O
+.......O..
+#....###..
+#OO..#....";
+
+#[test]
+fn day14_part1() {
+    let mut grid = parse(TEST_INPUT).unwrap();
+    grid.roll_round_stones_north();
+
+    assert_eq!(grid.total_load(), 136);
+}
+
+#[test]
+fn day14_part2() {
+    let mut grid = parse(TEST_INPUT).unwrap();
+    grid.spin_cycle(1000000000);
+
+    assert_eq!(grid.total_load(), 64);
+}
+
+#[test]
+fn day14_errors() {
+    assert_eq!(
+        Day14::parse("O..\n.x#").unwrap_err().to_string(),
+        "line 2, column 2: unexpected character `x`, expected `.`, `O` or `#`"
+    );
+}
@@ -0,0 +1,140 @@
+use aoc_core::{Error, Solution};
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    const DAY: u8 = 15;
+
+    type Input = String;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        let input = input.trim_end();
+
+        // Check the steps up front, so part 2 can rely on them being valid.
+        let mut offset = 0;
+        for step in input.split(',') {
+            if let Some((name, focal_length)) = step.split_once('=') {
+                let start = offset + name.len() + 1;
+                focal_length.parse::<usize>().map_err(|e| {
+                    Error::at(
+                        input,
+                        start..start + focal_length.len(),
+                        format!("invalid focal length `{focal_length}`: {e}"),
+                    )
+                })?;
+            } else if !step.ends_with('-') {
+                return Err(Error::at(
+                    input,
+                    offset..offset + step.len(),
+                    format!("invalid step `{step}`, expected e.g. `rn=1` or `cm-`"),
+                ));
+            }
+
+            offset += step.len() + 1;
+        }
+
+        Ok(input.to_string())
+    }
+
+    fn part1(input: &Self::Input) -> usize {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> usize {
+        part2(input)
+    }
+}
+
+pub fn part1(input: &str) -> usize {
+    input.split(',').map(|s| hash(s) as usize).sum()
+}
+
+#[derive(Debug, Clone)]
+struct Lens<'a> {
+    name: &'a str,
+    focal_length: usize,
+}
+
+pub fn part2(input: &str) -> usize {
+    // let mut lens_positions = HashMap::new();
+    let mut boxes: Vec<Vec<Lens>> = vec![vec![]; 256];
+
+    for s in input.split(',') {
+        if let Some(name) = s.strip_suffix('-') {
+            // Remove that one.
+            let box_id = hash(name) as usize;
+            let b: &mut Vec<Lens> = boxes.get_mut(box_id).unwrap();
+            b.retain(|l| l.name != name);
+        } else if let Some((name, focal_length)) = s.split_once('=') {
+            let box_id = hash(name) as usize;
+            let strength = focal_length.parse().unwrap();
+            let b: &mut Vec<Lens> = boxes.get_mut(box_id).unwrap();
+
+            if let Some(existing_lens) = b.iter_mut().find(|l| l.name == name) {
+                existing_lens.focal_length = strength;
+            } else {
+                let lens = Lens {
+                    name,
+                    focal_length: strength,
+                };
+                b.push(lens);
+            }
+        } else {
+            panic!("Invalid input: {}", s);
+        }
+    }
+
+    boxes
+        .iter()
+        .enumerate()
+        .flat_map(|(box_id, b)| {
+            b.iter()
+                .enumerate()
+                .map(move |(n, lens)| (1 + box_id) * (n + 1) * lens.focal_length)
+        })
+        .sum()
+}
+
+pub fn hash(input: &str) -> u8 {
+    let mut hash: usize = 0;
+    for c in input.bytes() {
+        hash += c as usize;
+        hash *= 17;
+        hash %= 256;
+    }
+
+    hash as u8
+}
+
+#[test]
+fn day15_part1() {
+    assert_eq!(hash("HASH"), 52);
+    assert_eq!(
+        part1("rn=1,cm-,qp=3,cm=2,qp-,pc=4,ot=9,ab=5,pc-,pc=6,ot=7"),
+        1320
+    )
+}
+
+#[test]
+fn day15_part2() {
+    assert_eq!(
+        part2("rn=1,cm-,qp=3,cm=2,qp-,pc=4,ot=9,ab=5,pc-,pc=6,ot=7"),
+        145
+    )
+}
+
+#[test]
+fn day15_errors() {
+    let error = |input| Day15::parse(input).unwrap_err().to_string();
+
+    assert_eq!(
+        error("rn=1,cm-,qp=x"),
+        "line 1, column 13: invalid focal length `x`: invalid digit found in string"
+    );
+    assert_eq!(
+        error("rn=1,cmx"),
+        "line 1, column 6: invalid step `cmx`, expected e.g. `rn=1` or `cm-`"
+    );
+}
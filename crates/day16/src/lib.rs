@@ -0,0 +1,253 @@
+use std::collections::HashSet;
+
+use aoc_core::{Error, Solution};
+use aoc_grid::{Direction, Position};
+use rayon::prelude::*;
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    const DAY: u8 = 16;
+
+    type Input = Grid;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        Grid::parse(input)
+    }
+
+    fn part1(grid: &Self::Input) -> usize {
+        grid.simulate_part1().len()
+    }
+
+    fn part2(grid: &Self::Input) -> usize {
+        grid.simulate_part2()
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+enum Cell {
+    Empty,
+    VerticalSplit,
+    HorizontalSplit,
+    LeftAngleMirror,
+    RightAngleMirror,
+}
+
+#[derive(Debug)]
+pub struct Grid {
+    cells: aoc_grid::Grid<Cell>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct Beam {
+    position: Position,
+    direction: Direction,
+}
+
+impl Grid {
+    pub fn parse(input: &str) -> Result<Self, Error> {
+        let cells = aoc_grid::Grid::parse(input, |c| match c {
+            '.' => Ok(Cell::Empty),
+            '|' => Ok(Cell::VerticalSplit),
+            '-' => Ok(Cell::HorizontalSplit),
+            '\\' => Ok(Cell::LeftAngleMirror),
+            '/' => Ok(Cell::RightAngleMirror),
+            _ => Err(format!(
+                "unexpected character `{c}`, expected `.`, `|`, `-`, `\\` or `/`"
+            )),
+        })?;
+        // The beam enters at the top left, so there has to be a cell there.
+        if cells.cells().is_empty() {
+            return Err(Error::new("the grid is empty"));
+        }
+
+        Ok(Self { cells })
+    }
+
+    pub fn simulate_part1(&self) -> HashSet<Position> {
+        self.simulate(Beam {
+            position: Position { x: 0, y: 0 },
+            direction: Direction::East,
+        })
+    }
+
+    pub fn simulate_part2(&self) -> usize {
+        // Find the starting beam that will go through the most cells
+        let width = self.cells.width();
+        let height = self.cells.height();
+
+        // Top row going down
+        (0..width)
+            .into_par_iter()
+            .map(|x| Beam {
+                position: Position { x, y: 0 },
+                direction: Direction::South,
+            })
+            // Left column going right
+            .chain((0..height).into_par_iter().map(|y| Beam {
+                position: Position { x: 0, y },
+                direction: Direction::East,
+            }))
+            // Bottom row going up
+            .chain((0..width).into_par_iter().map(|x| Beam {
+                position: Position { x, y: height - 1 },
+                direction: Direction::North,
+            }))
+            // Right column going left
+            .chain((0..height).into_par_iter().map(|y| Beam {
+                position: Position { x: width - 1, y },
+                direction: Direction::West,
+            }))
+            .map(|beam| self.simulate(beam).len())
+            .max()
+            .unwrap()
+    }
+
+    fn simulate(&self, initial_beam: Beam) -> HashSet<Position> {
+        let mut energized_fields = HashSet::new();
+        let mut beams_seen = HashSet::new();
+        let mut beams = vec![initial_beam];
+
+        let mut beams_to_remove = vec![];
+        loop {
+            let beam_count = beams.len();
+            if beam_count == 0 {
+                break;
+            }
+
+            for i in 0..beam_count {
+                let beam = &mut beams[i];
+
+                // If we've already seen a beam in this position, going in the same direction, remove it
+                // as continuing to simulate it would be pointless
+                if !beams_seen.insert((beam.position, beam.direction)) {
+                    beams_to_remove.push(i);
+                    continue;
+                }
+
+                energized_fields.insert(beam.position);
+
+                let cell = self.cells[beam.position];
+                let mut continue_in = |beam: &mut Beam, direction: Direction| match self
+                    .cells
+                    .step(beam.position, direction)
+                {
+                    Some(new_position) => {
+                        beam.position = new_position;
+                        beam.direction = direction;
+                    }
+                    None => {
+                        beams_to_remove.push(i);
+                    }
+                };
+                match cell {
+                    Cell::Empty => {
+                        continue_in(beam, beam.direction);
+                    }
+                    Cell::VerticalSplit => {
+                        // If going right or left, split into two beams going up and down
+                        // If going up or down, pass through
+                        match beam.direction {
+                            Direction::North | Direction::South => {
+                                continue_in(beam, beam.direction);
+                            }
+                            Direction::West | Direction::East => {
+                                let position = beam.position;
+                                continue_in(beam, Direction::North);
+                                if let Some(new_position) =
+                                    self.cells.step(position, Direction::South)
+                                {
+                                    beams.push(Beam {
+                                        position: new_position,
+                                        direction: Direction::South,
+                                    });
+                                }
+                            }
+                        }
+                    }
+                    Cell::HorizontalSplit => {
+                        // If going up or down, split into two beams going left and right
+                        // If going left or right, pass through
+                        match beam.direction {
+                            Direction::West | Direction::East => {
+                                continue_in(beam, beam.direction);
+                            }
+                            Direction::North | Direction::South => {
+                                let position = beam.position;
+                                continue_in(beam, Direction::West);
+                                if let Some(new_position) =
+                                    self.cells.step(position, Direction::East)
+                                {
+                                    beams.push(Beam {
+                                        position: new_position,
+                                        direction: Direction::East,
+                                    });
+                                }
+                            }
+                        }
+                    }
+                    Cell::LeftAngleMirror => {
+                        let new_direction = match beam.direction {
+                            Direction::East => Direction::South,
+                            Direction::South => Direction::East,
+                            Direction::West => Direction::North,
+                            Direction::North => Direction::West,
+                        };
+                        continue_in(beam, new_direction);
+                    }
+                    Cell::RightAngleMirror => {
+                        let new_direction = match beam.direction {
+                            Direction::East => Direction::North,
+                            Direction::South => Direction::West,
+                            Direction::West => Direction::South,
+                            Direction::North => Direction::East,
+                        };
+                        continue_in(beam, new_direction);
+                    }
+                }
+            }
+
+            for i in beams_to_remove.drain(..).rev() {
+                beams.swap_remove(i);
+            }
+        }
+
+        energized_fields
+    }
+}
+
+#[cfg(test)]
+const TEST_INPUT: &str = r#".|...\....
+|.-.\.....
+.....|-...
+........|.
+..........
+.........\
+..../.\\..
+.-.-/..|..
+.|....-|.\
+..//.|...."#;
+
+#[test]
+fn day16_part1() {
+    let grid = Grid::parse(TEST_INPUT).unwrap();
+    let energized_fields = grid.simulate_part1();
+    assert_eq!(energized_fields.len(), 46);
+}
+
+#[test]
+fn day16_part2() {
+    let grid = Grid::parse(TEST_INPUT).unwrap();
+    let energized_fields = grid.simulate_part2();
+    assert_eq!(energized_fields, 51);
+}
+
+#[test]
+fn day16_errors() {
+    assert_eq!(
+        Day16::parse(".|.\n.x/").unwrap_err().to_string(),
+        r"line 2, column 2: unexpected character `x`, expected `.`, `|`, `-`, `\` or `/`"
+    );
+}
@@ -0,0 +1,89 @@
+use std::{fmt, str::FromStr};
+
+use aoc_core::Error;
+use chumsky::prelude::*;
+
+use crate::{cubes_parser, Color};
+
+// How many cubes of each colour a bag holds, in the order they were given.
+// Colours that aren't mentioned aren't in the bag at all.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Bag {
+    cubes: Vec<(Color, u32)>,
+}
+
+impl Bag {
+    pub fn new(cubes: impl IntoIterator<Item = (Color, u32)>) -> Self {
+        let mut bag = Bag::default();
+        for (color, count) in cubes {
+            bag.set(color, count);
+        }
+
+        bag
+    }
+
+    // The bag from the puzzle: 12 red, 13 green and 14 blue cubes.
+    pub fn puzzle() -> Self {
+        Bag::new([
+            (Color::new("red"), 12),
+            (Color::new("green"), 13),
+            (Color::new("blue"), 14),
+        ])
+    }
+
+    pub fn get(&self, color: Color) -> u32 {
+        self.cubes
+            .iter()
+            .find(|(c, _)| *c == color)
+            .map_or(0, |(_, count)| *count)
+    }
+
+    pub fn set(&mut self, color: Color, count: u32) {
+        match self.cubes.iter_mut().find(|(c, _)| *c == color) {
+            Some((_, existing)) => *existing = count,
+            None => self.cubes.push((color, count)),
+        }
+    }
+
+    pub fn cubes(&self) -> impl Iterator<Item = (Color, u32)> + '_ {
+        self.cubes.iter().copied()
+    }
+}
+
+// e.g. `12 red, 13 green, 14 blue`
+impl FromStr for Bag {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        cubes_parser()
+            .then_ignore(end())
+            .parse(s)
+            .map(Bag::new)
+            .map_err(|e| Error::from_chumsky(s, e))
+    }
+}
+
+impl fmt::Display for Bag {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let cubes = self
+            .cubes
+            .iter()
+            .map(|(color, count)| format!("{count} {color}"))
+            .collect::<Vec<_>>();
+
+        f.pad(&cubes.join(", "))
+    }
+}
+
+#[test]
+fn bag_from_str() {
+    let bag = "12 red, 13 green,14 blue".parse::<Bag>().unwrap();
+    assert_eq!(bag, Bag::puzzle());
+    assert_eq!(bag.to_string(), "12 red, 13 green, 14 blue");
+    assert_eq!(bag.get(Color::new("yellow")), 0);
+
+    assert_eq!(
+        "12 red, green".parse::<Bag>().unwrap_err().to_string(),
+        "line 1, column 9: unexpected `g`, expected one of ` `, a digit (while parsing the cube count)"
+    );
+}
@@ -0,0 +1,81 @@
+use std::{
+    collections::HashMap,
+    fmt,
+    sync::{Arc, Mutex, OnceLock},
+};
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+// A cube colour. The names are interned, so colours are cheap to copy, compare
+// and hash however many samples mention them.
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Color(u32);
+
+// The names are shared between the two lookups, rather than leaked to make
+// them live as long as the program, as imported games can bring any number
+// of them.
+#[derive(Default)]
+struct Names {
+    names: Vec<Arc<str>>,
+    ids: HashMap<Arc<str>, u32>,
+}
+
+fn names() -> &'static Mutex<Names> {
+    static NAMES: OnceLock<Mutex<Names>> = OnceLock::new();
+    NAMES.get_or_init(Mutex::default)
+}
+
+impl Color {
+    pub fn new(name: &str) -> Self {
+        let mut names = names().lock().unwrap();
+        if let Some(&id) = names.ids.get(name) {
+            return Color(id);
+        }
+
+        let name: Arc<str> = name.into();
+        let id = names.names.len() as u32;
+        names.names.push(name.clone());
+        names.ids.insert(name, id);
+        Color(id)
+    }
+
+    pub fn name(self) -> Arc<str> {
+        names().lock().unwrap().names[self.0 as usize].clone()
+    }
+}
+
+impl fmt::Debug for Color {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Color({:?})", self.name())
+    }
+}
+
+impl fmt::Display for Color {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad(&self.name())
+    }
+}
+
+impl Serialize for Color {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.name())
+    }
+}
+
+impl<'de> Deserialize<'de> for Color {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let name = String::deserialize(deserializer)?;
+
+        Ok(Color::new(&name))
+    }
+}
+
+#[test]
+fn color_interning() {
+    let yellow = Color::new("yellow");
+
+    assert_eq!(yellow, Color::new("yellow"));
+    assert_ne!(yellow, Color::new("red"));
+    assert_eq!(&*yellow.name(), "yellow");
+    assert_eq!(format!("{yellow:>8}"), "  yellow");
+}
@@ -0,0 +1,250 @@
+use crate::{fewest_cubes, Bag, Color, Game, Sample};
+
+// How to estimate what's in a bag from the samples drawn from it.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Estimation {
+    // How many more cubes of each colour than the fewest possible the bag may
+    // hold. Every bag in that range is considered equally likely up front.
+    pub max_extra: u32,
+    // The probability the number of cubes falls in each colour's interval,
+    // e.g. `0.9`.
+    pub confidence: f64,
+}
+
+impl Default for Estimation {
+    fn default() -> Self {
+        Estimation {
+            max_extra: 10,
+            confidence: 0.9,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct BagEstimate {
+    // The bag that makes the samples most likely.
+    pub most_likely: Bag,
+    pub colors: Vec<ColorEstimate>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ColorEstimate {
+    pub color: Color,
+    // The fewest cubes of this colour the bag can hold.
+    pub fewest: u32,
+    pub mean: f64,
+    // The bounds, inclusive, the number of cubes is within with the requested
+    // confidence.
+    pub interval: (u32, u32),
+    // Whether the most likely number of cubes or the interval reaches the
+    // most the bag may hold, `fewest + max_extra`. The estimate then says
+    // more about `max_extra` than the samples, as the bag could well hold
+    // more cubes.
+    pub at_limit: bool,
+}
+
+pub fn estimate_game(game: &Game, estimation: &Estimation) -> BagEstimate {
+    estimate_bag(&game.samples, estimation)
+}
+
+// Treat the samples of all games as if they were drawn from the same bag.
+pub fn estimate_games(games: &[Game], estimation: &Estimation) -> BagEstimate {
+    let samples = games
+        .iter()
+        .flat_map(|game| &game.samples)
+        .collect::<Vec<_>>();
+
+    estimate_bag(samples, estimation)
+}
+
+// Each sample is a handful of cubes drawn from the bag without putting any
+// back, so the chance of seeing it follows the multivariate hypergeometric
+// distribution. The cubes go back in the bag before the next handful.
+//
+// Every bag up to `max_extra` more cubes of each colour than the fewest is
+// tried, which takes time exponential in the number of colours. The samples
+// say a lot about how the colours are mixed, but often little about how many
+// cubes there are in all, as a bigger bag with the same mix can be about as
+// likely or more. So the estimate of a colour can depend on `max_extra`, which
+// `at_limit` tells.
+pub fn estimate_bag<'a>(
+    samples: impl IntoIterator<Item = &'a Sample>,
+    estimation: &Estimation,
+) -> BagEstimate {
+    let samples = samples.into_iter().collect::<Vec<_>>();
+    let colors = fewest_cubes(samples.iter().copied())
+        .cubes()
+        .collect::<Vec<_>>();
+
+    // The number of cubes of each colour in every sample, in the same order
+    // as `colors`. These are the same totals the fewest cubes come from, so
+    // no sample draws more of a colour than the bag holds.
+    let draws = samples
+        .iter()
+        .map(|sample| {
+            let totals = sample.totals();
+            colors
+                .iter()
+                .map(|&(color, _)| totals.get(color))
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+
+    let largest_bag = colors
+        .iter()
+        .map(|(_, count)| count + estimation.max_extra)
+        .sum::<u32>();
+    let ln_factorials = (0..=largest_bag)
+        .scan(0.0, |ln_factorial: &mut f64, n| {
+            if n > 0 {
+                *ln_factorial += f64::from(n).ln();
+            }
+            Some(*ln_factorial)
+        })
+        .collect::<Vec<_>>();
+    let ln_choose = |n: u32, k: u32| {
+        ln_factorials[n as usize] - ln_factorials[k as usize] - ln_factorials[(n - k) as usize]
+    };
+
+    // The log likelihood of every bag, from the fewest cubes of each colour
+    // up to `max_extra` more.
+    let mut bags = Vec::new();
+    let mut bag = colors.iter().map(|(_, count)| *count).collect::<Vec<_>>();
+    loop {
+        let total = bag.iter().sum::<u32>();
+        let ln_likelihood = draws
+            .iter()
+            .map(|draw| {
+                let drawn = draw.iter().sum::<u32>();
+                bag.iter()
+                    .zip(draw)
+                    .map(|(&n, &k)| ln_choose(n, k))
+                    .sum::<f64>()
+                    - ln_choose(total, drawn)
+            })
+            .sum::<f64>();
+        bags.push((bag.clone(), ln_likelihood));
+
+        // Move on to the next bag, like counting with a digit per colour.
+        let next = bag
+            .iter_mut()
+            .zip(&colors)
+            .find_map(|(count, (_, fewest))| {
+                if *count < fewest + estimation.max_extra {
+                    *count += 1;
+                    Some(())
+                } else {
+                    *count = *fewest;
+                    None
+                }
+            });
+        if next.is_none() {
+            break;
+        }
+    }
+
+    // Turn the likelihoods into probabilities. The first of equally likely
+    // bags, the one with the fewest cubes, wins.
+    let most_likely = bags.iter().fold(
+        &bags[0],
+        |best, bag| if bag.1 > best.1 { bag } else { best },
+    );
+    let max = most_likely.1;
+    let total = bags.iter().map(|(_, ln)| (ln - max).exp()).sum::<f64>();
+    let probability = |ln: f64| (ln - max).exp() / total;
+
+    let tail = (1.0 - estimation.confidence) / 2.0;
+    let estimates = colors
+        .iter()
+        .enumerate()
+        .map(|(i, &(color, fewest))| {
+            let mut distribution = vec![0.0; estimation.max_extra as usize + 1];
+            for (bag, ln) in &bags {
+                distribution[(bag[i] - fewest) as usize] += probability(*ln);
+            }
+
+            let mean = distribution
+                .iter()
+                .zip(fewest..)
+                .map(|(p, count)| p * f64::from(count))
+                .sum();
+            let quantile = |q: f64| {
+                let mut cumulative = 0.0;
+                for (p, count) in distribution.iter().zip(fewest..) {
+                    cumulative += p;
+                    if cumulative >= q - 1e-12 {
+                        return count;
+                    }
+                }
+                fewest + estimation.max_extra
+            };
+
+            let limit = fewest + estimation.max_extra;
+            let interval = (quantile(tail), quantile(1.0 - tail));
+
+            ColorEstimate {
+                color,
+                fewest,
+                mean,
+                interval,
+                at_limit: most_likely.0[i] == limit || interval.1 == limit,
+            }
+        })
+        .collect();
+
+    BagEstimate {
+        most_likely: Bag::new(
+            colors
+                .iter()
+                .zip(&most_likely.0)
+                .map(|(&(color, _), &count)| (color, count)),
+        ),
+        colors: estimates,
+    }
+}
+
+#[test]
+fn estimate_example_games() {
+    use chumsky::Parser;
+
+    let games = crate::games_parser().parse(crate::TEST_INPUT).unwrap();
+
+    // The first game's samples don't rule out any bag with the same mix of
+    // colours, however big, so every colour runs into the limit.
+    let estimate = estimate_game(&games[0], &Estimation::default());
+    assert_eq!(
+        estimate.colors.iter().map(|c| c.fewest).collect::<Vec<_>>(),
+        [6, 4, 2]
+    );
+    assert!(estimate.colors.iter().all(|color| color.at_limit));
+    assert_eq!(estimate, estimate_game(&games[0], &Estimation::default()));
+
+    // Red is all there is in the first sample, so more red is never less
+    // likely, but a single blue cube among a few red ones is telling.
+    let games = crate::games_parser()
+        .parse("Game 1: 3 red, 2 red; 1 blue")
+        .unwrap();
+    let estimate = estimate_game(&games[0], &Estimation::default());
+    let [red, blue] = &estimate.colors[..] else {
+        panic!("expected two colours, got {:?}", estimate.colors);
+    };
+    assert_eq!((red.fewest, red.at_limit), (5, true));
+    assert_eq!(
+        (blue.fewest, blue.interval, blue.at_limit),
+        (1, (1, 10), false)
+    );
+    assert_eq!(estimate.most_likely.get(blue.color), 3);
+
+    // With no room for extra cubes all that's left is the minimal bag.
+    let estimation = Estimation {
+        max_extra: 0,
+        ..Estimation::default()
+    };
+    let games = crate::games_parser().parse(crate::TEST_INPUT).unwrap();
+    let estimate = estimate_games(&games, &estimation);
+    assert_eq!(
+        estimate.most_likely.to_string(),
+        "15 blue, 20 red, 13 green"
+    );
+    assert_eq!(estimate.colors[1].interval, (20, 20));
+}
@@ -0,0 +1,191 @@
+use std::{
+    fmt,
+    io::{Read, Write},
+};
+
+use aoc_core::Error;
+use serde::{
+    de::{MapAccess, Visitor},
+    ser::SerializeMap,
+    Deserialize, Deserializer, Serialize, Serializer,
+};
+
+use crate::{Color, Game, Sample};
+
+// A sample is written as a map from colour to count, e.g.
+// `{"blue": 3, "red": 4}`, keeping the colours in the order they were drawn.
+// A colour listed more than once is written once with the counts added up, as
+// most JSON readers only keep the last of a repeated key.
+impl Serialize for Sample {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut amounts: Vec<(Color, u32)> = Vec::new();
+        for &(color, count) in &self.amounts {
+            match amounts.iter_mut().find(|(c, _)| *c == color) {
+                Some((_, total)) => *total += count,
+                None => amounts.push((color, count)),
+            }
+        }
+
+        let mut map = serializer.serialize_map(Some(amounts.len()))?;
+        for (color, count) in &amounts {
+            map.serialize_entry(color, count)?;
+        }
+        map.end()
+    }
+}
+
+impl<'de> Deserialize<'de> for Sample {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct SampleVisitor;
+
+        impl<'de> Visitor<'de> for SampleVisitor {
+            type Value = Sample;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str("a map from cube colour to count")
+            }
+
+            fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Sample, A::Error> {
+                let mut amounts = Vec::new();
+                while let Some(entry) = map.next_entry()? {
+                    amounts.push(entry);
+                }
+
+                Ok(Sample { amounts })
+            }
+        }
+
+        deserializer.deserialize_map(SampleVisitor)
+    }
+}
+
+// A row of the CSV export: one colour of one sample. Samples are numbered
+// from 1, like they're shown in reports.
+#[derive(Debug, Serialize, Deserialize)]
+struct Record {
+    game: u32,
+    sample: usize,
+    color: Color,
+    count: u32,
+}
+
+pub fn write_json(games: &[Game], mut writer: impl Write) -> Result<(), Error> {
+    serde_json::to_writer_pretty(&mut writer, games)
+        .map_err(|e| Error::new(format!("failed to write JSON: {e}")))?;
+    writeln!(writer).map_err(|e| Error::new(format!("failed to write JSON: {e}")))
+}
+
+pub fn read_json(reader: impl Read) -> Result<Vec<Game>, Error> {
+    serde_json::from_reader(reader).map_err(|e| Error::new(format!("invalid JSON: {e}")))
+}
+
+// Samples without any cubes have no rows, so they're left out.
+pub fn write_csv(games: &[Game], writer: impl Write) -> Result<(), Error> {
+    let write_error = |e: csv::Error| Error::new(format!("failed to write CSV: {e}"));
+
+    let mut writer = csv::Writer::from_writer(writer);
+    for game in games {
+        for (index, sample) in game.samples.iter().enumerate() {
+            for &(color, count) in &sample.amounts {
+                writer
+                    .serialize(Record {
+                        game: game.id,
+                        sample: index + 1,
+                        color,
+                        count,
+                    })
+                    .map_err(write_error)?;
+            }
+        }
+    }
+
+    writer
+        .flush()
+        .map_err(|e| Error::new(format!("failed to write CSV: {e}")))
+}
+
+// Rows are grouped into games and samples by their numbers, so they can be in
+// any order. Games are kept in the order they first show up, and samples are
+// sorted by number.
+pub fn read_csv(reader: impl Read) -> Result<Vec<Game>, Error> {
+    let mut games: Vec<(u32, Vec<(usize, Sample)>)> = Vec::new();
+    for record in csv::Reader::from_reader(reader).deserialize() {
+        let record: Record = record.map_err(|e| Error::new(format!("invalid CSV: {e}")))?;
+
+        let samples = match games.iter_mut().position(|(id, _)| *id == record.game) {
+            Some(i) => &mut games[i].1,
+            None => {
+                games.push((record.game, Vec::new()));
+                &mut games.last_mut().unwrap().1
+            }
+        };
+        let sample = match samples.iter().position(|(n, _)| *n == record.sample) {
+            Some(i) => &mut samples[i].1,
+            None => {
+                samples.push((
+                    record.sample,
+                    Sample {
+                        amounts: Vec::new(),
+                    },
+                ));
+                &mut samples.last_mut().unwrap().1
+            }
+        };
+        sample.amounts.push((record.color, record.count));
+    }
+
+    Ok(games
+        .into_iter()
+        .map(|(id, mut samples)| {
+            samples.sort_by_key(|(n, _)| *n);
+            Game {
+                id,
+                samples: samples.into_iter().map(|(_, sample)| sample).collect(),
+            }
+        })
+        .collect())
+}
+
+#[test]
+fn export_round_trip() {
+    use chumsky::Parser;
+
+    let games = crate::games_parser().parse(crate::TEST_INPUT).unwrap();
+
+    let mut json = Vec::new();
+    write_json(&games, &mut json).unwrap();
+    assert!(String::from_utf8_lossy(&json).contains("\"blue\": 3,\n        \"red\": 4"));
+    assert_eq!(read_json(json.as_slice()).unwrap(), games);
+
+    let mut csv = Vec::new();
+    write_csv(&games, &mut csv).unwrap();
+    let csv = String::from_utf8(csv).unwrap();
+    assert!(csv.starts_with("game,sample,color,count\n1,1,blue,3\n1,1,red,4\n1,2,red,1\n"));
+    assert_eq!(read_csv(csv.as_bytes()).unwrap(), games);
+
+    let text = games
+        .iter()
+        .map(|game| game.to_string())
+        .collect::<Vec<_>>()
+        .join("\n");
+    assert_eq!(text, crate::TEST_INPUT);
+
+    let games = crate::games_parser()
+        .parse("Game 1: 3 red, 1 blue, 2 red")
+        .unwrap();
+    let mut json = Vec::new();
+    write_json(&games, &mut json).unwrap();
+    let json = String::from_utf8(json).unwrap();
+    assert!(json.contains("\"red\": 5,\n        \"blue\": 1\n"));
+    assert_eq!(
+        read_json(json.as_bytes()).unwrap()[0].to_string(),
+        "Game 1: 5 red, 1 blue"
+    );
+
+    assert_eq!(
+        read_csv("game,sample,color,count\n1,1,red,lots\n".as_bytes())
+            .unwrap_err()
+            .to_string(),
+        "invalid CSV: CSV deserialize error: record 1 (line: 2, byte: 24): field 3: invalid digit found in string"
+    );
+}
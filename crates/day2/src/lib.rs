@@ -0,0 +1,289 @@
+use aoc_core::{text::lines, Error, Solution};
+use chumsky::{prelude::*, text::newline};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+mod bag;
+mod color;
+mod estimate;
+mod export;
+
+pub use bag::Bag;
+pub use color::Color;
+pub use estimate::{
+    estimate_bag, estimate_game, estimate_games, BagEstimate, ColorEstimate, Estimation,
+};
+pub use export::{read_csv, read_json, write_csv, write_json};
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    const DAY: u8 = 2;
+
+    type Input = Vec<Game>;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        games_parser()
+            .parse(input)
+            .map_err(|e| Error::from_chumsky(input, e))
+    }
+
+    fn part1(games: &Self::Input) -> u32 {
+        possible_games_sum(games, &Bag::puzzle())
+    }
+
+    fn part2(games: &Self::Input) -> u32 {
+        game_powers_sum(games, &Bag::puzzle())
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Game {
+    id: u32,
+    samples: Vec<Sample>,
+}
+
+impl Game {
+    pub fn id(&self) -> u32 {
+        self.id
+    }
+}
+
+// Written the way the puzzle input has it, e.g.
+// `Game 1: 3 blue, 4 red; 1 red, 2 green`.
+impl fmt::Display for Game {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let samples = self
+            .samples
+            .iter()
+            .map(|sample| {
+                sample
+                    .amounts
+                    .iter()
+                    .map(|(color, count)| format!("{count} {color}"))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            })
+            .collect::<Vec<_>>();
+
+        write!(f, "Game {}: {}", self.id, samples.join("; "))
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Sample {
+    amounts: Vec<(Color, u32)>,
+}
+
+impl Sample {
+    // The cubes of each colour shown, adding up a colour listed more than
+    // once, e.g. `3 red, 2 red`.
+    fn totals(&self) -> Bag {
+        let mut totals = Bag::default();
+        for &(color, count) in &self.amounts {
+            totals.set(color, totals.get(color) + count);
+        }
+
+        totals
+    }
+}
+
+fn number() -> impl Parser<char, u32, Error = Simple<char>> + Clone {
+    text::int(10).try_map(|n: String, span| {
+        n.parse::<u32>()
+            .map_err(|e| Simple::custom(span, format!("invalid number `{n}`: {e}")))
+    })
+}
+
+// Only allow spaces, as newlines separate the games.
+fn spaces() -> impl Parser<char, (), Error = Simple<char>> + Clone {
+    just(' ').repeated().ignored()
+}
+
+// A list of cube counts like `3 blue, 4 red`.
+fn cubes_parser() -> impl Parser<char, Vec<(Color, u32)>, Error = Simple<char>> + Clone {
+    let count = number()
+        .then_ignore(just(' ').repeated().at_least(1))
+        .then(text::ident().map(|name: String| Color::new(&name)))
+        .map(|(n, color)| (color, n));
+
+    count
+        .labelled("cube count")
+        .separated_by(just(',').padded_by(spaces()))
+}
+
+pub fn games_parser() -> impl Parser<char, Vec<Game>, Error = Simple<char>> {
+    let number = number();
+    let sample = cubes_parser().map(|amounts| Sample { amounts });
+
+    let game = text::keyword("Game")
+        .padded_by(spaces())
+        .ignore_then(number.labelled("game id"))
+        .then_ignore(just(':').padded_by(spaces()))
+        .then(sample.separated_by(just(';').padded_by(spaces())))
+        .map(|(id, samples)| Game { id, samples });
+
+    lines(game)
+        .then_ignore(newline().repeated())
+        .then_ignore(end())
+}
+
+pub fn possible_games_sum(games: &[Game], bag: &Bag) -> u32 {
+    games
+        .iter()
+        .filter(|game| is_game_possible(game, bag))
+        .map(|game| game.id)
+        .sum()
+}
+
+pub fn is_game_possible(game: &Game, bag: &Bag) -> bool {
+    violations(game, bag).next().is_none()
+}
+
+// What checking a game against a bag found.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GameReport {
+    pub id: u32,
+    pub minimal_bag: Bag,
+    // Every time the game showed more cubes of a colour than the bag holds.
+    pub violations: Vec<Violation>,
+}
+
+impl GameReport {
+    pub fn is_possible(&self) -> bool {
+        self.violations.is_empty()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Violation {
+    // The index of the offending sample in the game, counting from 0.
+    pub sample: usize,
+    pub color: Color,
+    pub shown: u32,
+    pub limit: u32,
+}
+
+impl Violation {
+    pub fn excess(&self) -> u32 {
+        self.shown - self.limit
+    }
+}
+
+pub fn check_games(games: &[Game], bag: &Bag) -> Vec<GameReport> {
+    games.iter().map(|game| check_game(game, bag)).collect()
+}
+
+pub fn check_game(game: &Game, bag: &Bag) -> GameReport {
+    GameReport {
+        id: game.id,
+        minimal_bag: minimal_bag(game),
+        violations: violations(game, bag).collect(),
+    }
+}
+
+fn violations<'a>(game: &'a Game, bag: &'a Bag) -> impl Iterator<Item = Violation> + 'a {
+    game.samples
+        .iter()
+        .enumerate()
+        .flat_map(move |(index, sample)| {
+            let totals = sample.totals().cubes().collect::<Vec<_>>();
+            totals.into_iter().filter_map(move |(color, shown)| {
+                let limit = bag.get(color);
+                (shown > limit).then_some(Violation {
+                    sample: index,
+                    color,
+                    shown,
+                    limit,
+                })
+            })
+        })
+}
+
+pub fn game_powers_sum(games: &[Game], bag: &Bag) -> u32 {
+    games.iter().map(|game| game_power(game, bag)).sum()
+}
+
+// The fewest cubes of each colour the game could have been played with.
+pub fn minimal_bag(game: &Game) -> Bag {
+    fewest_cubes(&game.samples)
+}
+
+fn fewest_cubes<'a>(samples: impl IntoIterator<Item = &'a Sample>) -> Bag {
+    let mut bag = Bag::default();
+    for sample in samples {
+        for (color, amount) in sample.totals().cubes() {
+            bag.set(color, amount.max(bag.get(color)));
+        }
+    }
+
+    bag
+}
+
+// The number of cubes of each of the bag's colours in the minimal bag,
+// multiplied together. A colour the game never shows counts as none, making
+// the power 0.
+pub fn game_power(game: &Game, bag: &Bag) -> u32 {
+    let minimal_bag = minimal_bag(game);
+
+    bag.cubes()
+        .map(|(color, _)| minimal_bag.get(color))
+        .product()
+}
+
+#[cfg(test)]
+const TEST_INPUT: &str = "Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
+Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue
+Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red
+Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red
+Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green";
+
+#[test]
+fn day2_part1() {
+    let games = games_parser().parse(TEST_INPUT).unwrap();
+
+    assert_eq!(games.len(), 5);
+    assert_eq!(possible_games_sum(&games, &Bag::puzzle()), 8);
+}
+
+#[test]
+fn day2_part2() {
+    let games = games_parser().parse(TEST_INPUT).unwrap();
+
+    assert_eq!(game_powers_sum(&games, &Bag::puzzle()), 2286);
+
+    let games = games_parser()
+        .parse("Game 1: 3 red, 2 green\nGame 2:")
+        .unwrap();
+    assert_eq!(game_power(&games[0], &Bag::puzzle()), 0);
+    assert_eq!(game_power(&games[1], &Bag::puzzle()), 0);
+}
+
+#[test]
+fn day2_check_games() {
+    let games = games_parser().parse(TEST_INPUT).unwrap();
+    let reports = check_games(&games, &Bag::puzzle());
+
+    assert_eq!(reports[0].minimal_bag.to_string(), "6 blue, 4 red, 2 green");
+    assert!(reports[0].is_possible());
+    assert_eq!(
+        reports[2].violations,
+        [Violation {
+            sample: 0,
+            color: Color::new("red"),
+            shown: 20,
+            limit: 12,
+        }]
+    );
+    assert_eq!(reports[2].violations[0].excess(), 8);
+
+    let games = games_parser()
+        .parse("Game 1: 2 yellow, 1 red; 3 yellow\nGame 2: 5 purple\n")
+        .unwrap();
+    let bag = "3 yellow, 1 red".parse().unwrap();
+    assert!(is_game_possible(&games[0], &bag));
+    assert!(!is_game_possible(&games[1], &bag));
+    assert_eq!(game_power(&games[0], &bag), 3);
+}
@@ -1,128 +1,179 @@
-use chumsky::{prelude::*, text::newline};
-use std::collections::HashMap;
-
-fn main() {
-    let input = include_str!("input.txt");
-    let games = games_parser().parse(input).unwrap();
-
-    println!("possible games sum: {}", possible_games_sum(&games));
-    println!("game powers sum: {}", game_powers_sum(&games));
-}
-
-#[derive(Debug)]
-struct Game {
-    id: u32,
-    samples: Vec<Sample>,
-}
-
-#[derive(Debug, PartialEq, Eq, Hash)]
-enum Color {
-    Red,
-    Green,
-    Blue,
-}
-
-#[derive(Debug)]
-struct Sample {
-    amounts: HashMap<Color, u32>,
+use std::{
+    io::{self, Write},
+    process,
+};
+
+use aoc_core::{
+    input::{self, InputSource},
+    Solution,
+};
+use clap::{Parser, ValueEnum};
+use day2::{
+    check_games, estimate_game, estimate_games, game_powers_sum, possible_games_sum, read_csv,
+    read_json, write_csv, write_json, Bag, BagEstimate, Day2, Estimation,
+};
+
+#[derive(Debug, Parser)]
+#[command(about = "Day 2: Cube Conundrum")]
+struct Args {
+    /// Read the input from this file, or from stdin if `-`
+    input: Option<String>,
+
+    /// What the input is written as
+    #[arg(long, value_enum, default_value_t = Format::Text)]
+    from: Format,
+
+    /// Print the games in this format instead of solving the puzzle
+    #[arg(long, value_enum)]
+    export: Option<Format>,
+
+    /// The cubes in the bag to check the games against. Its colours are the
+    /// ones multiplied for the power of a game
+    #[arg(long, default_value = "12 red, 13 green, 14 blue")]
+    bag: Bag,
+
+    /// Show for each game the fewest cubes it needs, and what rules it out
+    #[arg(long)]
+    report: bool,
+
+    /// Estimate what's in the bag of each game, and of all games together,
+    /// from the cubes drawn from it
+    #[arg(long)]
+    estimate: bool,
+
+    /// How many more cubes of each colour than the fewest possible a bag may
+    /// hold when estimating
+    #[arg(long, default_value_t = 10)]
+    max_extra: u32,
+
+    /// How likely the number of cubes should be to fall in the estimated
+    /// interval
+    #[arg(long, default_value_t = 0.9)]
+    confidence: f64,
 }
 
-fn games_parser() -> impl Parser<char, Vec<Game>, Error = Simple<char>> {
-    let red = text::int(10)
-        .map(|n: String| n.parse().unwrap())
-        .then_ignore(just(" red"))
-        .map(|n| (Color::Red, n));
-    let green = text::int(10)
-        .map(|n: String| n.parse().unwrap())
-        .then_ignore(just(" green"))
-        .map(|n| (Color::Green, n));
-    let blue = text::int(10)
-        .map(|n: String| n.parse().unwrap())
-        .then_ignore(just(" blue"))
-        .map(|n| (Color::Blue, n));
-
-    let sample = choice((red, green, blue))
-        .separated_by(just(',').padded())
-        .collect()
-        .map(|amounts| Sample { amounts });
-
-    text::keyword("Game")
-        .padded()
-        .ignore_then(text::int(10))
-        .map(|n: String| n.parse().unwrap())
-        .then_ignore(just(':').padded())
-        .then(sample.separated_by(just(';').padded()))
-        .map(|(id, samples)| Game { id, samples })
-        .separated_by(newline())
-        .allow_trailing()
-        .then_ignore(end())
-        .collect()
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum Format {
+    /// The puzzle's own format, a game per line
+    Text,
+    /// An array of `{id, samples}` objects, with a colour to count map per
+    /// sample
+    Json,
+    /// A `game,sample,color,count` row per colour in each sample
+    Csv,
 }
 
-fn possible_games_sum(games: &[Game]) -> u32 {
-    games
-        .iter()
-        .filter(|game| {
-            // Find all games that at no point revealed more than 12 red cubes, 13
-            // green cubes, or 14 blue cubes.
-            is_game_possible(game, 12, 13, 14)
-        })
-        .map(|game| game.id)
-        .sum()
-}
-
-fn is_game_possible(game: &Game, red: u32, green: u32, blue: u32) -> bool {
-    game.samples.iter().all(|sample| {
-        sample.amounts.get(&Color::Red).unwrap_or(&0) <= &red
-            && sample.amounts.get(&Color::Green).unwrap_or(&0) <= &green
-            && sample.amounts.get(&Color::Blue).unwrap_or(&0) <= &blue
-    })
-}
-
-fn game_powers_sum(games: &[Game]) -> u32 {
-    games.iter().map(game_power).sum()
-}
+fn main() {
+    let args = Args::parse();
+
+    let source = args
+        .input
+        .map_or(InputSource::Default, |arg| InputSource::from_arg(&arg));
+    let input = input::read_or_exit(&source, Day2::DAY);
+    let games = match args.from {
+        Format::Text => Ok(aoc_core::parse_or_exit::<Day2>(&input)),
+        Format::Json => read_json(input.as_bytes()),
+        Format::Csv => read_csv(input.as_bytes()),
+    }
+    .unwrap_or_else(|e| exit_with(e));
+
+    if let Some(format) = args.export {
+        let mut stdout = io::stdout().lock();
+        let result = match format {
+            Format::Text => games
+                .iter()
+                .try_for_each(|game| writeln!(stdout, "{game}"))
+                .map_err(|e| aoc_core::Error::new(format!("failed to write the games: {e}"))),
+            Format::Json => write_json(&games, stdout),
+            Format::Csv => write_csv(&games, stdout),
+        };
+        if let Err(e) = result {
+            exit_with(e);
+        }
 
-fn game_power(game: &Game) -> u32 {
-    // Find the lowest possible number of cubes that could have been used in
-    // this game. Then multiply them together to get the game's power.
-    let mut red = 0;
-    let mut green = 0;
-    let mut blue = 0;
+        return;
+    }
 
-    for sample in game.samples.iter() {
-        if let Some(r) = sample.amounts.get(&Color::Red) {
-            red = red.max(*r);
+    if args.report {
+        println!(
+            "{:>4}  {:<8}  {:<28}  {:<12}",
+            "game", "possible", "minimal bag", "ruled out by"
+        );
+        for report in check_games(&games, &args.bag) {
+            let ruled_out_by = report
+                .violations
+                .iter()
+                .map(|violation| {
+                    format!(
+                        "sample {}: {} {} ({} over)",
+                        violation.sample + 1,
+                        violation.shown,
+                        violation.color,
+                        violation.excess()
+                    )
+                })
+                .collect::<Vec<_>>();
+
+            println!(
+                "{:>4}  {:<8}  {:<28}  {}",
+                report.id,
+                if report.is_possible() { "yes" } else { "no" },
+                report.minimal_bag,
+                ruled_out_by.join("; ")
+            );
         }
-        if let Some(g) = sample.amounts.get(&Color::Green) {
-            green = green.max(*g);
+        println!();
+    }
+
+    if args.estimate {
+        let estimation = Estimation {
+            max_extra: args.max_extra,
+            confidence: args.confidence,
+        };
+        println!(
+            "{:>4}  {:<8}  {:>6}  {:>9}  {:>6}  {:>8}",
+            "game", "colour", "fewest", "likeliest", "mean", "interval"
+        );
+        let mut at_limit = false;
+        for game in &games {
+            at_limit |= print_estimate(&game.id().to_string(), &estimate_game(game, &estimation));
         }
-        if let Some(b) = sample.amounts.get(&Color::Blue) {
-            blue = blue.max(*b);
+        at_limit |= print_estimate("all", &estimate_games(&games, &estimation));
+        if at_limit {
+            println!(
+                "* reaches {} more cubes than the fewest, the most tried; the bag may hold more",
+                args.max_extra
+            );
         }
+        println!();
     }
 
-    red * green * blue
+    println!(
+        "possible games sum: {}",
+        possible_games_sum(&games, &args.bag)
+    );
+    println!("game powers sum: {}", game_powers_sum(&games, &args.bag));
 }
 
-#[cfg(test)]
-const TEST_INPUT: &str = "Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
-Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue
-Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red
-Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red
-Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green";
-
-#[test]
-fn day2_part1() {
-    let games = games_parser().parse(TEST_INPUT).unwrap();
+// Returns whether any colour's estimate reached the limit, which is marked.
+fn print_estimate(game: &str, estimate: &BagEstimate) -> bool {
+    for color in &estimate.colors {
+        println!(
+            "{:>4}  {:<8}  {:>6}  {:>9}  {:>6.1}  {:>8}{}",
+            game,
+            color.color,
+            color.fewest,
+            estimate.most_likely.get(color.color),
+            color.mean,
+            format!("{}..={}", color.interval.0, color.interval.1),
+            if color.at_limit { " *" } else { "" }
+        );
+    }
 
-    assert_eq!(games.len(), 5);
-    assert_eq!(possible_games_sum(&games), 8);
+    estimate.colors.iter().any(|color| color.at_limit)
 }
 
-#[test]
-fn day2_part2() {
-    let games = games_parser().parse(TEST_INPUT).unwrap();
-
-    assert_eq!(game_powers_sum(&games), 2286);
+fn exit_with(e: aoc_core::Error) -> ! {
+    eprintln!("error: {e:#}");
+    process::exit(1);
 }
@@ -0,0 +1,416 @@
+use aoc_core::{Error, Solution};
+use aoc_grid::Position;
+
+mod rules;
+mod symbols;
+
+pub use rules::{Arity, Combine, Rule};
+use symbols::Mark;
+pub use symbols::Symbols;
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    const DAY: u8 = 3;
+
+    type Input = Grid;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        Grid::parse(input)
+    }
+
+    fn part1(grid: &Self::Input) -> u64 {
+        grid.numbers_adjacent_to_symbols().sum()
+    }
+
+    fn part2(grid: &Self::Input) -> u64 {
+        Self::try_part2(grid).unwrap_or_else(|e| panic!("{e}"))
+    }
+
+    fn try_part2(grid: &Self::Input) -> Result<u64, Error> {
+        grid.gear_ratios_sum()
+    }
+}
+
+#[derive(Debug)]
+pub struct Grid {
+    numbers: Vec<Number>,
+    grid: aoc_grid::Grid<GridCell>,
+}
+
+// A number in the schematic. Numbers never continue onto the next row, so
+// `start` and `end`, the positions of the first and last digits, are always in
+// the same row.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Number {
+    pub value: u32,
+    pub start: Position,
+    pub end: Position,
+}
+
+#[derive(Debug)]
+enum GridCell {
+    Number(usize),
+    Symbol(char),
+    Empty,
+}
+
+impl Grid {
+    pub fn parse(input: &str) -> Result<Self, Error> {
+        Grid::parse_with(input, &Symbols::default())
+    }
+
+    pub fn parse_with(input: &str, symbols: &Symbols) -> Result<Self, Error> {
+        // Check the rows are all as wide first, so the numbers can be found
+        // row by row.
+        let chars = aoc_grid::Grid::parse(input, Ok)?;
+
+        let mut numbers = Vec::new();
+        let mut cells = Vec::with_capacity(chars.cells().len());
+        let mut errors = Vec::new();
+        for ((y, row), line) in chars.rows().enumerate().zip(input.lines()) {
+            let byte = |x: usize| line.char_indices().nth(x).map_or(line.len(), |(i, _)| i);
+
+            let mut x = 0;
+            for run in row.chunk_by(|a, b| a.is_ascii_digit() == b.is_ascii_digit()) {
+                let (start, end) = (x, x + run.len());
+                x = end;
+
+                if !run[0].is_ascii_digit() {
+                    for (x, &c) in (start..).zip(run) {
+                        match symbols.mark(c) {
+                            Ok(Mark::Blank) => cells.push(GridCell::Empty),
+                            Ok(Mark::Symbol(c)) => cells.push(GridCell::Symbol(c)),
+                            Err(e) => {
+                                errors.push(Error::in_line(y, line, byte(x)..byte(x + 1), e));
+                                cells.push(GridCell::Empty);
+                            }
+                        }
+                    }
+                    continue;
+                }
+
+                let value = run.iter().try_fold(0u32, |n, c| {
+                    n.checked_mul(10)?.checked_add(c.to_digit(10).unwrap())
+                });
+                let Some(value) = value else {
+                    errors.push(Error::in_line(
+                        y,
+                        line,
+                        byte(start)..byte(end),
+                        "number is too large",
+                    ));
+                    cells.extend(run.iter().map(|_| GridCell::Empty));
+                    continue;
+                };
+
+                cells.extend(run.iter().map(|_| GridCell::Number(numbers.len())));
+                numbers.push(Number {
+                    value,
+                    start: Position::new(start, y),
+                    end: Position::new(end - 1, y),
+                });
+            }
+        }
+        if let Some(e) = Error::combine(errors) {
+            return Err(e);
+        }
+
+        Ok(Self {
+            numbers,
+            grid: aoc_grid::Grid::new(chars.width(), cells),
+        })
+    }
+
+    pub fn numbers(&self) -> &[Number] {
+        &self.numbers
+    }
+
+    // The numbers with a symbol next to them, including diagonally, in the
+    // order they appear.
+    pub fn part_numbers(&self) -> impl Iterator<Item = PartNumber> + '_ {
+        self.numbers.iter().filter_map(|&number| {
+            let symbols = self
+                .around(number)
+                .filter_map(|position| match self.grid[position] {
+                    GridCell::Symbol(symbol) => Some((position, symbol)),
+                    _ => None,
+                })
+                .collect::<Vec<_>>();
+
+            (!symbols.is_empty()).then_some(PartNumber { number, symbols })
+        })
+    }
+
+    pub fn part_numbers_next_to(&self, symbol: char) -> impl Iterator<Item = PartNumber> + '_ {
+        self.part_numbers()
+            .filter(move |part| part.is_next_to(symbol))
+    }
+
+    // The `*`s next to exactly two numbers, in the order they appear.
+    pub fn gears(&self) -> impl Iterator<Item = Gear> + '_ {
+        self.gears_by(&[Rule::GEAR])
+    }
+
+    // The symbols that are gears by one of the rules, in the order they
+    // appear. A symbol is only checked against the first rule for it that
+    // applies.
+    pub fn gears_by<'a>(&'a self, rules: &'a [Rule]) -> impl Iterator<Item = Gear> + 'a {
+        self.grid.iter().filter_map(move |(position, cell)| {
+            let &GridCell::Symbol(symbol) = cell else {
+                return None;
+            };
+
+            let parts = self
+                .numbers_around(position)
+                .into_iter()
+                .map(|n| self.numbers[n])
+                .collect::<Vec<_>>();
+            let rule = rules
+                .iter()
+                .find(|rule| rule.applies_to(symbol, parts.len()))?;
+
+            Some(Gear {
+                position,
+                symbol,
+                value: rule.value(&parts),
+                parts,
+            })
+        })
+    }
+
+    pub fn numbers_adjacent_to_symbols(&self) -> impl Iterator<Item = u64> + '_ {
+        self.part_numbers().map(|part| u64::from(part.number.value))
+    }
+
+    pub fn gear_ratios_sum(&self) -> Result<u64, Error> {
+        self.gear_value_sum(&[Rule::GEAR])
+    }
+
+    // The values of the gears by `rules` added up, or an error for the first
+    // gear whose value, or the sum so far, doesn't fit in a `u64`.
+    pub fn gear_value_sum(&self, rules: &[Rule]) -> Result<u64, Error> {
+        self.gears_by(rules).try_fold(0u64, |sum, gear| {
+            gear.value
+                .and_then(|value| sum.checked_add(value))
+                .ok_or_else(|| {
+                    Error::new(format!(
+                        "the value of the `{}` gear at line {}, column {} is too large",
+                        gear.symbol,
+                        gear.position.y + 1,
+                        gear.position.x + 1
+                    ))
+                })
+        })
+    }
+
+    // The positions around a number, row by row.
+    fn around(&self, number: Number) -> impl Iterator<Item = Position> + '_ {
+        let columns = number.start.x.saturating_sub(1)..=number.end.x + 1;
+
+        (number.start.y.saturating_sub(1)..=number.start.y + 1)
+            .flat_map(move |y| columns.clone().map(move |x| Position::new(x, y)))
+            .filter(|&position| self.grid.contains(position))
+    }
+
+    // The indices of the numbers next to a position, each only once.
+    fn numbers_around(&self, position: Position) -> Vec<usize> {
+        let mut numbers = Vec::new();
+        for neighbour in self.grid.neighbours8(position) {
+            if let GridCell::Number(n) = self.grid[neighbour] {
+                if !numbers.contains(&n) {
+                    numbers.push(n);
+                }
+            }
+        }
+
+        numbers
+    }
+}
+
+// A number with at least one symbol next to it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PartNumber {
+    pub number: Number,
+    // The symbols around the number, row by row.
+    pub symbols: Vec<(Position, char)>,
+}
+
+impl PartNumber {
+    pub fn is_next_to(&self, symbol: char) -> bool {
+        self.symbols.iter().any(|&(_, s)| s == symbol)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Gear {
+    pub position: Position,
+    pub symbol: char,
+    // The part numbers next to the gear, in the order they appear.
+    pub parts: Vec<Number>,
+    // The part numbers combined by the gear's rule, or `None` if that doesn't
+    // fit in a `u64`.
+    pub value: Option<u64>,
+}
+
+#[cfg(test)]
+const TEST_INPUT: &str = "467..114..
+...*......
+..35..633.
+......#...
+617*......
+.....+.58.
+..592.....
+......755.
+...$.*....
+.664.598..";
+
+#[test]
+fn day3_part1() {
+    let grid = Grid::parse(TEST_INPUT).unwrap();
+
+    assert_eq!(grid.numbers_adjacent_to_symbols().sum::<u64>(), 4361);
+
+    let grid = Grid::parse("4000000000*4000000000").unwrap();
+    assert_eq!(Day3::part1(&grid), 8_000_000_000);
+}
+
+#[test]
+fn day3_part2() {
+    let grid = Grid::parse(TEST_INPUT).unwrap();
+
+    assert_eq!(grid.gear_ratios_sum(), Ok(467835));
+}
+
+#[test]
+fn day3_symbols() {
+    let grid = Grid::parse("1!.\n.^2").unwrap();
+    assert_eq!(grid.numbers_adjacent_to_symbols().sum::<u64>(), 3);
+
+    let symbols = Symbols {
+        blank: ' ',
+        only: Some(vec!['*', '#']),
+    };
+    let grid = Grid::parse_with("1* \n #2", &symbols).unwrap();
+    assert_eq!(grid.gear_ratios_sum(), Ok(2));
+
+    let error = Grid::parse_with("1*.\n!#2", &symbols).unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "line 1, column 3: unexpected character `.`, expected a digit, ' ' or one of `*`, `#` (and 1 more error)"
+    );
+    assert_eq!(error.others()[0].location().unwrap().line, 2);
+
+    assert_eq!(
+        Grid::parse("1\t.").unwrap_err().to_string(),
+        "line 1, column 2: unexpected character '\\t', expected a digit, `.` or a symbol"
+    );
+}
+
+#[test]
+fn day3_numbers() {
+    let grid = Grid::parse("..12\n34*.\n...5").unwrap();
+    assert_eq!(
+        grid.numbers(),
+        [
+            Number {
+                value: 12,
+                start: Position::new(2, 0),
+                end: Position::new(3, 0)
+            },
+            Number {
+                value: 34,
+                start: Position::new(0, 1),
+                end: Position::new(1, 1)
+            },
+            Number {
+                value: 5,
+                start: Position::new(3, 2),
+                end: Position::new(3, 2)
+            },
+        ]
+    );
+    assert_eq!(grid.numbers_adjacent_to_symbols().sum::<u64>(), 51);
+
+    assert_eq!(
+        Grid::parse("1.\n2.3").unwrap_err().to_string(),
+        "line 2, column 3: row is 3 characters wide, expected 2"
+    );
+    assert_eq!(
+        Grid::parse("1..\n2.").unwrap_err().to_string(),
+        "line 2, column 3: row is only 2 characters wide, expected 3"
+    );
+    assert_eq!(
+        Grid::parse("1.99999999999\n*............")
+            .unwrap_err()
+            .to_string(),
+        "line 1, column 3: number is too large"
+    );
+}
+
+#[test]
+fn day3_parts_and_gears() {
+    let grid = Grid::parse(TEST_INPUT).unwrap();
+
+    let parts = grid.part_numbers().collect::<Vec<_>>();
+    assert_eq!(parts.len(), 8);
+    assert_eq!(parts[0].number.value, 467);
+    assert_eq!(parts[0].symbols, [(Position::new(3, 1), '*')]);
+
+    let next_to_hash = grid
+        .part_numbers_next_to('#')
+        .map(|part| (part.number.value, part.number.start))
+        .collect::<Vec<_>>();
+    assert_eq!(next_to_hash, [(633, Position::new(6, 2))]);
+
+    let gears = grid.gears().collect::<Vec<_>>();
+    assert_eq!(gears.len(), 2);
+    assert_eq!(gears[1].position, Position::new(5, 8));
+    let parts = gears[1].parts.iter().map(|part| part.value);
+    assert_eq!(parts.collect::<Vec<_>>(), [755, 598]);
+
+    // The same value in two places is two part numbers.
+    let grid = Grid::parse("12.12\n..$..").unwrap();
+    assert_eq!(grid.part_numbers().count(), 2);
+}
+
+#[test]
+fn day3_gear_rules() {
+    let grid = Grid::parse(TEST_INPUT).unwrap();
+
+    let rules = [
+        "*=1:sum".parse().unwrap(),
+        "*>=2:max".parse().unwrap(),
+        "$>=1:product".parse().unwrap(),
+    ];
+    let gears = grid
+        .gears_by(&rules)
+        .map(|gear| (gear.symbol, gear.value))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        gears,
+        [
+            ('*', Some(467)),
+            ('*', Some(617)),
+            ('$', Some(664)),
+            ('*', Some(755))
+        ]
+    );
+
+    let grid = Grid::parse(
+        "4000000000.4000000000
+..........*..........
+..........4000000000.",
+    )
+    .unwrap();
+    let rules = ["*>=3:product".parse().unwrap()];
+    assert_eq!(grid.gears_by(&rules).next().unwrap().value, None);
+    assert_eq!(
+        grid.gear_value_sum(&rules).unwrap_err().to_string(),
+        "the value of the `*` gear at line 2, column 11 is too large"
+    );
+    let rules = ["*>=3:sum".parse().unwrap()];
+    assert_eq!(grid.gear_value_sum(&rules), Ok(12_000_000_000));
+}
@@ -1,181 +1,134 @@
-use std::collections::HashSet;
-
-fn main() {
-    let grid = Grid::parse(include_str!("input.txt"));
-
-    println!(
-        "part 1: {}",
-        grid.numbers_adjacent_to_symbols().sum::<u32>(),
-    );
-    println!("part 2: {}", grid.gear_ratios().sum::<u32>(),);
-}
-
-#[derive(Debug)]
-struct Grid {
-    numbers: Vec<u32>,
-    grid_width: usize,
-    grid: Vec<GridCell>,
-}
-
-#[derive(Debug)]
-enum GridCell {
-    Number(usize),
-    Symbol(char),
-    Empty,
+use std::process;
+
+use aoc_core::{
+    input::{self, InputSource},
+    Error, Solution,
+};
+use aoc_grid::Position;
+use clap::Parser;
+use day3::{Day3, Grid, PartNumber, Rule, Symbols};
+
+#[derive(Debug, Parser)]
+#[command(about = "Day 3: Gear Ratios")]
+struct Args {
+    /// Read the input from this file, or from stdin if `-`
+    input: Option<String>,
+
+    /// The character that marks an empty cell
+    #[arg(long, default_value_t = '.')]
+    blank: char,
+
+    /// The only characters that are symbols, e.g. `*#$`. By default every
+    /// character that's not a digit, the blank or whitespace is one
+    #[arg(long)]
+    symbols: Option<String>,
+
+    /// List the part numbers, where they are and the symbols next to them
+    #[arg(long)]
+    parts: bool,
+
+    /// Only list the part numbers next to this symbol
+    #[arg(long, requires = "parts")]
+    next_to: Option<char>,
+
+    /// List the gears, where they are and their part numbers, by the rules
+    /// given or else the puzzle's
+    #[arg(long)]
+    gears: bool,
+
+    /// Find gears by this rule, e.g. `#>=3:sum` for a `#` next to at
+    /// least three numbers, added up. Rules are tried in order, and can
+    /// combine numbers by `product`, `sum` or `max`
+    #[arg(long = "rule", value_name = "RULE")]
+    rules: Vec<Rule>,
 }
 
-impl Grid {
-    fn parse(input: &str) -> Self {
-        let mut numbers = vec![];
-        let mut lines = input.lines().peekable();
-        let grid_width = lines.peek().map(|l| l.len()).unwrap_or(0);
-        let mut grid = Vec::with_capacity(input.len());
-
-        let mut number_buffer = String::new();
-
-        for line in lines {
-            for c in line.chars() {
-                if c.is_ascii_digit() {
-                    number_buffer.push(c);
-                    grid.push(GridCell::Number(numbers.len()));
-                } else {
-                    if !number_buffer.is_empty() {
-                        numbers.push(number_buffer.parse().unwrap());
-                        number_buffer.clear();
-                    }
-
-                    grid.push(match c {
-                        '.' => GridCell::Empty,
-                        '*' | '+' | '#' | '$' | '-' | '&' | '@' | '/' | '=' | '%' => {
-                            GridCell::Symbol(c)
-                        }
-                        _ => panic!("unexpected character: {}", c),
-                    });
-                }
-            }
-        }
-
-        Self {
-            numbers,
-            grid_width,
-            grid,
+fn main() {
+    let args = Args::parse();
+
+    let source = args
+        .input
+        .map_or(InputSource::Default, |arg| InputSource::from_arg(&arg));
+    let input = input::read_or_exit(&source, Day3::DAY);
+    let symbols = Symbols {
+        blank: args.blank,
+        only: args.symbols.map(|symbols| symbols.chars().collect()),
+    };
+    let grid = Grid::parse_with(&input, &symbols).unwrap_or_else(|e| {
+        eprintln!("error: failed to parse input for day {}: {e:#}", Day3::DAY);
+        process::exit(1);
+    });
+
+    if args.parts {
+        println!("{:>6}  {:<9}  symbols", "number", "at");
+        let parts: Box<dyn Iterator<Item = PartNumber>> = match args.next_to {
+            Some(symbol) => Box::new(grid.part_numbers_next_to(symbol)),
+            None => Box::new(grid.part_numbers()),
+        };
+        for part in parts {
+            let symbols = part
+                .symbols
+                .iter()
+                .map(|&(position, symbol)| format!("`{symbol}` at {}", at(position)))
+                .collect::<Vec<_>>();
+
+            println!(
+                "{:>6}  {:<9}  {}",
+                part.number.value,
+                at(part.number.start),
+                symbols.join(", ")
+            );
         }
+        println!();
     }
 
-    fn numbers_adjacent_to_symbols(&self) -> impl Iterator<Item = u32> + '_ {
-        let mut number_indices = HashSet::new();
-
-        for (i, cell) in self.grid.iter().enumerate() {
-            if let GridCell::Symbol(_) = cell {
-                // Find the numbers adjacent to this symbol.
-                for j in self.adjacent_indices(i) {
-                    if let GridCell::Number(n) = self.grid[j] {
-                        number_indices.insert(n);
-                    }
-                }
-            }
+    if args.gears {
+        // Without any rules of our own, list the puzzle's gears.
+        let rules = match &args.rules[..] {
+            [] => &[Rule::GEAR][..],
+            rules => rules,
+        };
+        println!("{:<9}  {:<6}  {:>8}  parts", "gear at", "symbol", "value");
+        for gear in grid.gears_by(rules) {
+            let parts = gear
+                .parts
+                .iter()
+                .map(|part| part.value.to_string())
+                .collect::<Vec<_>>();
+
+            let value = gear
+                .value
+                .map_or_else(|| "too large".to_string(), |value| value.to_string());
+
+            println!(
+                "{:<9}  {:<6}  {:>8}  {}",
+                at(gear.position),
+                gear.symbol,
+                value,
+                parts.join(", ")
+            );
         }
-
-        number_indices.into_iter().map(move |i| self.numbers[i])
+        println!();
     }
 
-    fn gear_ratios(&self) -> impl Iterator<Item = u32> + '_ {
-        // Find all '*' cells that are adjacent to exactly two numbers. Multiply
-        // those together to get the gear ratio.
-        self.grid.iter().enumerate().filter_map(move |(i, cell)| {
-            if let GridCell::Symbol('*') = cell {
-                let adjacent_numbers: HashSet<usize> = self
-                    .adjacent_indices(i)
-                    .into_iter()
-                    .filter_map(|j| {
-                        if let GridCell::Number(n) = self.grid[j] {
-                            Some(n)
-                        } else {
-                            None
-                        }
-                    })
-                    .collect();
-
-                if adjacent_numbers.len() == 2 {
-                    Some(
-                        adjacent_numbers
-                            .into_iter()
-                            .map(|n| self.numbers[n])
-                            .product(),
-                    )
-                } else {
-                    None
-                }
-            } else {
-                None
-            }
-        })
-    }
-
-    fn adjacent_indices(&self, i: usize) -> Vec<usize> {
-        let x = i % self.grid_width;
-        let y = i / self.grid_width;
-
-        let mut indices = Vec::with_capacity(8);
-
-        if x > 0 {
-            indices.push(i - 1);
-        }
-
-        if x < self.grid_width - 1 {
-            indices.push(i + 1);
-        }
-
-        if y > 0 {
-            indices.push(i - self.grid_width);
-
-            if x > 0 {
-                indices.push(i - self.grid_width - 1);
-            }
-
-            if x < self.grid_width - 1 {
-                indices.push(i - self.grid_width + 1);
-            }
-        }
-
-        if y < self.grid.len() / self.grid_width - 1 {
-            indices.push(i + self.grid_width);
-
-            if x > 0 {
-                indices.push(i + self.grid_width - 1);
-            }
-
-            if x < self.grid_width - 1 {
-                indices.push(i + self.grid_width + 1);
-            }
-        }
-
-        indices
+    println!(
+        "part 1: {}",
+        grid.numbers_adjacent_to_symbols().sum::<u64>(),
+    );
+    print_sum("part 2", grid.gear_ratios_sum());
+    if !args.rules.is_empty() {
+        print_sum("gear rules", grid.gear_value_sum(&args.rules));
     }
 }
 
-#[cfg(test)]
-const TEST_INPUT: &str = "467..114..
-...*......
-..35..633.
-......#...
-617*......
-.....+.58.
-..592.....
-......755.
-...$.*....
-.664.598..";
-
-#[test]
-fn day3_part1() {
-    let grid = Grid::parse(TEST_INPUT);
-
-    assert_eq!(grid.numbers_adjacent_to_symbols().sum::<u32>(), 4361);
+fn print_sum(name: &str, sum: Result<u64, Error>) {
+    match sum {
+        Ok(sum) => println!("{name}: {sum}"),
+        Err(e) => eprintln!("{name}: {e}"),
+    }
 }
 
-#[test]
-fn day3_part2() {
-    let grid = Grid::parse(TEST_INPUT);
-
-    assert_eq!(grid.gear_ratios().sum::<u32>(), 467835);
+// Positions are shown counting from 1, like in error messages.
+fn at(position: Position) -> String {
+    format!("{}:{}", position.y + 1, position.x + 1)
 }
@@ -0,0 +1,133 @@
+use std::{fmt, str::FromStr};
+
+use crate::Number;
+
+// When a symbol is a gear, and what its value is.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Rule {
+    pub symbol: char,
+    pub arity: Arity,
+    pub combine: Combine,
+}
+
+// How many numbers must be next to the symbol for it to be a gear.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Arity {
+    Exactly(usize),
+    AtLeast(usize),
+}
+
+// How the numbers next to a gear make up its value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Combine {
+    Product,
+    Sum,
+    Max,
+}
+
+impl Rule {
+    // The puzzle's gears: a `*` next to exactly two numbers, multiplied.
+    pub const GEAR: Rule = Rule {
+        symbol: '*',
+        arity: Arity::Exactly(2),
+        combine: Combine::Product,
+    };
+
+    pub fn applies_to(&self, symbol: char, numbers: usize) -> bool {
+        symbol == self.symbol
+            && match self.arity {
+                Arity::Exactly(n) => numbers == n,
+                Arity::AtLeast(n) => numbers >= n,
+            }
+    }
+
+    // `None` if the value doesn't fit in a `u64`, which products of a few
+    // large numbers easily don't.
+    pub fn value(&self, numbers: &[Number]) -> Option<u64> {
+        let mut values = numbers.iter().map(|number| u64::from(number.value));
+        match self.combine {
+            Combine::Product => values.try_fold(1u64, |a, b| a.checked_mul(b)),
+            Combine::Sum => values.try_fold(0u64, |a, b| a.checked_add(b)),
+            Combine::Max => Some(values.max().unwrap_or(0)),
+        }
+    }
+}
+
+// e.g. `*=2:product` or `#>=3:sum`
+impl FromStr for Rule {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || format!("invalid rule `{s}`, expected e.g. `*=2:product` or `#>=3:sum`");
+
+        let mut chars = s.chars();
+        let symbol = chars.next().ok_or_else(invalid)?;
+        let (arity, combine) = chars.as_str().split_once(':').ok_or_else(invalid)?;
+        let arity = match arity.strip_prefix(">=") {
+            Some(n) => Arity::AtLeast(n.parse().map_err(|_| invalid())?),
+            None => Arity::Exactly(
+                arity
+                    .strip_prefix('=')
+                    .and_then(|n| n.parse().ok())
+                    .ok_or_else(invalid)?,
+            ),
+        };
+        let combine = match combine {
+            "product" => Combine::Product,
+            "sum" => Combine::Sum,
+            "max" => Combine::Max,
+            _ => {
+                return Err(format!(
+                    "unknown operation `{combine}`, expected `product`, `sum` or `max`"
+                ))
+            }
+        };
+
+        Ok(Rule {
+            symbol,
+            arity,
+            combine,
+        })
+    }
+}
+
+impl fmt::Display for Rule {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let arity = match self.arity {
+            Arity::Exactly(n) => format!("={n}"),
+            Arity::AtLeast(n) => format!(">={n}"),
+        };
+        let combine = match self.combine {
+            Combine::Product => "product",
+            Combine::Sum => "sum",
+            Combine::Max => "max",
+        };
+
+        f.pad(&format!("{}{arity}:{combine}", self.symbol))
+    }
+}
+
+#[test]
+fn rule_from_str() {
+    let rule = "#>=3:sum".parse::<Rule>().unwrap();
+    assert_eq!(
+        rule,
+        Rule {
+            symbol: '#',
+            arity: Arity::AtLeast(3),
+            combine: Combine::Sum
+        }
+    );
+    assert_eq!(rule.to_string(), "#>=3:sum");
+    assert_eq!("*=2:product".parse(), Ok(Rule::GEAR));
+    assert_eq!("==1:max".parse::<Rule>().unwrap().symbol, '=');
+
+    assert_eq!(
+        "*2:product".parse::<Rule>(),
+        Err("invalid rule `*2:product`, expected e.g. `*=2:product` or `#>=3:sum`".to_string())
+    );
+    assert_eq!(
+        "*=2:min".parse::<Rule>(),
+        Err("unknown operation `min`, expected `product`, `sum` or `max`".to_string())
+    );
+}
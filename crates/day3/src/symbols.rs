@@ -0,0 +1,75 @@
+use std::fmt;
+
+// Which characters of a schematic are symbols, and which one marks an empty
+// cell. Digits are always part of numbers.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Symbols {
+    pub blank: char,
+    // The only characters that are symbols. With `None`, every character
+    // that's not a digit, the blank or whitespace is one.
+    pub only: Option<Vec<char>>,
+}
+
+impl Default for Symbols {
+    fn default() -> Self {
+        Symbols {
+            blank: '.',
+            only: None,
+        }
+    }
+}
+
+// What a character that isn't a digit stands for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Mark {
+    Blank,
+    Symbol(char),
+}
+
+impl Symbols {
+    pub(crate) fn mark(&self, c: char) -> Result<Mark, String> {
+        if c == self.blank {
+            return Ok(Mark::Blank);
+        }
+
+        match &self.only {
+            Some(only) if only.contains(&c) => Ok(Mark::Symbol(c)),
+            None if !c.is_whitespace() => Ok(Mark::Symbol(c)),
+            _ => Err(format!(
+                "unexpected character {}, expected a digit, {} or {self}",
+                Quoted(c),
+                Quoted(self.blank)
+            )),
+        }
+    }
+}
+
+// How the allowed symbols are described in errors, e.g. "one of `*`, `#`".
+impl fmt::Display for Symbols {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.only {
+            Some(only) => {
+                let only = only
+                    .iter()
+                    .map(|&c| Quoted(c).to_string())
+                    .collect::<Vec<_>>();
+                write!(f, "one of {}", only.join(", "))
+            }
+            None => write!(f, "a symbol"),
+        }
+    }
+}
+
+// Characters are shown in backticks, unless they're whitespace, which is
+// easier to make out escaped.
+struct Quoted(char);
+
+impl fmt::Display for Quoted {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.0.is_whitespace() {
+            write!(f, "{:?}", self.0)
+        } else {
+            write!(f, "`{}`", self.0)
+        }
+    }
+}
@@ -0,0 +1,123 @@
+use std::collections::HashSet;
+
+use aoc_core::Solution;
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use day4::{count_cards, Day4, ScratchCard};
+
+// Cards shaped like the puzzle's: ten winning numbers and twenty-five of the
+// card's own, all between 1 and 99. Each card is worked out from its id alone,
+// walking 1..=99 from a different start with a step that shares no factor with
+// 99, so its numbers never repeat. The matches follow a fixed pattern in which
+// most cards win nothing, which keeps the copies in part 2 from growing
+// without bound.
+fn synthetic_input(cards: usize) -> String {
+    const STEPS: [usize; 8] = [2, 5, 7, 13, 17, 23, 29, 31];
+    const MATCHES: [usize; 10] = [0, 0, 3, 0, 1, 0, 5, 0, 2, 1];
+
+    let mut input = String::new();
+    for id in 1..=cards {
+        let (start, step) = (id * 31, STEPS[id % STEPS.len()]);
+        let numbers = (0..35)
+            .map(|i| 1 + ((start + i * step) % 99) as u32)
+            .collect::<Vec<_>>();
+        let (winning, others) = numbers.split_at(10);
+        let matches = MATCHES[id % MATCHES.len()];
+        let mut own = winning[..matches].to_vec();
+        own.extend(&others[..25 - matches]);
+
+        let line = |numbers: &[u32]| {
+            numbers
+                .iter()
+                .map(|n| format!("{n:2}"))
+                .collect::<Vec<_>>()
+                .join(" ")
+        };
+        input.push_str(&format!(
+            "Card {id:6}: {} | {}\n",
+            line(winning),
+            line(&own)
+        ));
+    }
+
+    input
+}
+
+// What day 4 did before number sets: a pair of `HashSet`s per card, built
+// from the parsed cards and intersected again each time the matches are
+// needed.
+fn hashset_answers(cards: &[(HashSet<u32>, HashSet<u32>)]) -> (u32, usize) {
+    let matches = |(winning, own): &(HashSet<u32>, HashSet<u32>)| winning.intersection(own).count();
+
+    let score = cards
+        .iter()
+        .map(|card| match matches(card) as u32 {
+            0 => 0,
+            n => 2u32.pow(n - 1),
+        })
+        .sum();
+
+    let mut counts = vec![1usize; cards.len()];
+    for (i, card) in cards.iter().enumerate() {
+        for other in i + 1..(i + 1 + matches(card)).min(cards.len()) {
+            counts[other] += counts[i];
+        }
+    }
+
+    (score, counts.iter().sum())
+}
+
+fn answers(cards: &[ScratchCard]) -> (u32, usize) {
+    (
+        cards.iter().map(|c| c.score()).sum(),
+        count_cards(cards).unwrap(),
+    )
+}
+
+fn cards(c: &mut Criterion) {
+    let mut group = c.benchmark_group("cards");
+    for count in [1_000, 100_000, 500_000] {
+        let input = synthetic_input(count);
+        let cards = Day4::parse(&input).unwrap();
+        let hashed = cards
+            .iter()
+            .map(|card| {
+                (
+                    card.winning_numbers().iter().collect(),
+                    card.card_numbers().iter().collect(),
+                )
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(hashset_answers(&hashed), answers(&cards));
+
+        group.throughput(Throughput::Elements(count as u64));
+        // Parsing is much slower than the rest, so only time it on the
+        // smallest input.
+        if count == 1_000 {
+            group.bench_with_input(BenchmarkId::new("parse", count), &input, |b, input| {
+                b.iter(|| Day4::parse(input))
+            });
+        }
+        group.bench_with_input(BenchmarkId::new("hashset", count), &hashed, |b, cards| {
+            b.iter(|| hashset_answers(cards))
+        });
+        group.bench_with_input(
+            BenchmarkId::new("bitset_intersection", count),
+            &cards,
+            |b, cards| {
+                b.iter(|| {
+                    cards
+                        .iter()
+                        .map(|card| card.winning_numbers().intersection_len(card.card_numbers()))
+                        .sum::<usize>()
+                })
+            },
+        );
+        group.bench_with_input(BenchmarkId::new("cached", count), &cards, |b, cards| {
+            b.iter(|| answers(cards))
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, cards);
+criterion_main!(benches);
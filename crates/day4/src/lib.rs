@@ -0,0 +1,396 @@
+use aoc_core::{text::lines, Error, Solution};
+use chumsky::prelude::*;
+
+mod numbers;
+mod validation;
+
+pub use numbers::NumberSet;
+pub use validation::Validation;
+use validation::{check_raw_ids, check_strict, id_errors, RawCard};
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    const DAY: u8 = 4;
+
+    type Input = Vec<ScratchCard>;
+    type Answer1 = u32;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        let cards = raw_cards(input)?;
+        check_raw_ids(input, &cards)?;
+
+        Ok(into_cards(cards))
+    }
+
+    fn part1(cards: &Self::Input) -> u32 {
+        cards.iter().map(|c| c.score()).sum()
+    }
+
+    fn part2(cards: &Self::Input) -> usize {
+        count_cards(cards).unwrap_or_else(|e| panic!("{e}"))
+    }
+}
+
+pub fn count_cards(cards: &[ScratchCard]) -> Result<usize, Error> {
+    Ok(cascade(cards)?.iter().map(|card| card.copies).sum())
+}
+
+// How many copies of a card there are in the end, and where they came from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CardCopies {
+    pub id: u32,
+    pub matches: usize,
+    // Including the original card.
+    pub copies: usize,
+    // The cards that won copies of this one, by id, and how many they won.
+    pub won_from: Vec<(u32, usize)>,
+}
+
+// The ids must go up from the first one without gaps, in whatever order the
+// cards are given.
+pub fn check_ids(cards: &[ScratchCard]) -> Result<(), Error> {
+    let ids = cards.iter().map(|card| (card.id, ()));
+
+    match Error::combine(id_errors(ids, |(), message| Error::new(message))) {
+        Some(e) => Err(e),
+        None => Ok(()),
+    }
+}
+
+// Play out the cards in order of their ids, whatever order they're given in.
+// Each card wins a copy of as many of the cards right after it as it has
+// matches, for every copy of it there is. Copies of cards past the last one
+// aren't won. The ids are checked like `check_ids` does.
+pub fn cascade(cards: &[ScratchCard]) -> Result<Vec<CardCopies>, Error> {
+    check_ids(cards)?;
+
+    let mut by_id = cards.iter().collect::<Vec<_>>();
+    by_id.sort_by_key(|card| card.id);
+
+    let mut report = by_id
+        .iter()
+        .map(|card| CardCopies {
+            id: card.id,
+            matches: card.winning_number_count(),
+            copies: 1,
+            won_from: Vec::new(),
+        })
+        .collect::<Vec<_>>();
+    for (i, card) in by_id.iter().enumerate() {
+        let copies = report[i].copies;
+        for other in report
+            .iter_mut()
+            .skip(i + 1)
+            .take(card.winning_number_count())
+        {
+            other.copies += copies;
+            other.won_from.push((card.id, copies));
+        }
+    }
+
+    Ok(report)
+}
+
+#[derive(Debug, Clone)]
+pub struct ScratchCard {
+    id: u32,
+    winning_numbers: NumberSet,
+    card_numbers: NumberSet,
+    // How many of the card's numbers are winning numbers, counted once up
+    // front as the card can't change.
+    matches: usize,
+}
+
+impl ScratchCard {
+    pub fn new(id: u32, winning_numbers: NumberSet, card_numbers: NumberSet) -> Self {
+        let matches = winning_numbers.intersection_len(&card_numbers);
+
+        ScratchCard {
+            id,
+            winning_numbers,
+            card_numbers,
+            matches,
+        }
+    }
+
+    pub fn id(&self) -> u32 {
+        self.id
+    }
+
+    pub fn winning_numbers(&self) -> &NumberSet {
+        &self.winning_numbers
+    }
+
+    pub fn card_numbers(&self) -> &NumberSet {
+        &self.card_numbers
+    }
+
+    pub fn score(&self) -> u32 {
+        match self.winning_number_count() as u32 {
+            0 => 0,
+            n => 2u32.pow(n - 1),
+        }
+    }
+
+    pub fn winning_number_count(&self) -> usize {
+        self.matches
+    }
+}
+
+pub fn parse_cards(input: &str, validation: Validation) -> Result<Vec<ScratchCard>, Error> {
+    let cards = raw_cards(input)?;
+    if validation == Validation::Strict {
+        check_strict(input, &cards)?;
+    }
+
+    Ok(into_cards(cards))
+}
+
+fn raw_cards(input: &str) -> Result<Vec<RawCard>, Error> {
+    raw_cards_parser()
+        .parse(input)
+        .map_err(|e| Error::from_chumsky(input, e))
+}
+
+pub fn cards_parser() -> impl Parser<char, Vec<ScratchCard>, Error = Simple<char>> {
+    raw_cards_parser().map(into_cards)
+}
+
+fn raw_cards_parser() -> impl Parser<char, Vec<RawCard>, Error = Simple<char>> {
+    let number = text::int(10)
+        .try_map(|n: String, span| {
+            n.parse::<u32>()
+                .map_err(|e| Simple::custom(span, format!("invalid number `{n}`: {e}")))
+        })
+        .map_with_span(|n, span| (n, span));
+    // Only allow spaces, as newlines separate the cards.
+    let spaces = just(' ').repeated();
+    let numbers = number.separated_by(spaces.at_least(1));
+
+    let card = text::keyword("Card")
+        .padded_by(spaces)
+        .ignore_then(number.labelled("card id"))
+        .then_ignore(just(':').padded_by(spaces))
+        .then(numbers.labelled("winning numbers"))
+        .then_ignore(just('|').padded_by(spaces))
+        .then(numbers.labelled("card numbers"))
+        .map(|((id, winning_numbers), card_numbers)| RawCard {
+            id,
+            winning_numbers,
+            card_numbers,
+        });
+
+    lines(card)
+        .then_ignore(text::newline().repeated())
+        .then_ignore(end())
+}
+
+// The widest range of numbers the sets are kept as bits for, 128 bytes each.
+// The puzzle's numbers fit in far fewer.
+const MAX_BITS_RANGE: u64 = 1024;
+
+// Every number set covers the range of numbers on all the cards, so any two
+// can be intersected word by word. If the numbers are spread too widely for
+// that, each set is hashed instead, rather than every card taking bits for the
+// whole range.
+fn into_cards(cards: Vec<RawCard>) -> Vec<ScratchCard> {
+    let all_numbers = || {
+        cards
+            .iter()
+            .flat_map(|card| card.winning_numbers.iter().chain(&card.card_numbers))
+            .map(|&(n, _)| n)
+    };
+    let range = match (all_numbers().min(), all_numbers().max()) {
+        (Some(min), Some(max)) if u64::from(max - min) < MAX_BITS_RANGE => Some(min..max + 1),
+        (Some(_), Some(_)) => None,
+        _ => Some(NumberSet::DEFAULT_RANGE),
+    };
+
+    let numbers = |numbers: Vec<(u32, _)>| {
+        let numbers = numbers.into_iter().map(|(n, _)| n);
+        match &range {
+            Some(range) => {
+                let mut set = NumberSet::with_range(range.clone());
+                set.extend(numbers);
+
+                set
+            }
+            None => NumberSet::Hashed(numbers.collect()),
+        }
+    };
+
+    cards
+        .into_iter()
+        .map(|card| {
+            ScratchCard::new(
+                card.id.0,
+                numbers(card.winning_numbers),
+                numbers(card.card_numbers),
+            )
+        })
+        .collect()
+}
+
+#[cfg(test)]
+const TEST_INPUT: &str = "Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53
+Card 2: 13 32 20 16 61 | 61 30 68 82 17 32 24 19
+Card 3:  1 21 53 59 44 | 69 82 63 72 16 21 14  1
+Card 4: 41 92 73 84 69 | 59 84 76 51 58  5 54 83
+Card 5: 87 83 26 28 32 | 88 30 70 12 93 22 82 36
+Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11";
+
+#[test]
+fn day4_part1() {
+    let cards = cards_parser().parse(TEST_INPUT).unwrap();
+
+    assert_eq!(cards.len(), 6);
+    assert_eq!(cards[0].score(), 8);
+    assert_eq!(cards[1].score(), 2);
+    assert_eq!(cards[5].score(), 0);
+
+    assert_eq!(cards.iter().map(|c| c.score()).sum::<u32>(), 13);
+}
+
+#[test]
+fn day4_large_numbers() {
+    let cards = cards_parser()
+        .parse("Card 1: 5000 5001 | 5001 6000\nCard 2: 6000 | 5000")
+        .unwrap();
+
+    assert_eq!(cards[0].winning_numbers().range(), Some(5000..6024));
+    assert!(cards
+        .iter()
+        .flat_map(|card| [card.winning_numbers(), card.card_numbers()])
+        .all(|numbers| matches!(numbers, NumberSet::Bits { .. })));
+    assert_eq!(cards[0].score(), 1);
+    assert_eq!(cards[1].score(), 0);
+}
+
+#[test]
+fn day4_spread_numbers() {
+    let cards = cards_parser()
+        .parse("Card 1: 0 | 1\nCard 2: 4000000000 | 1 4000000000")
+        .unwrap();
+
+    assert!(cards
+        .iter()
+        .flat_map(|card| [card.winning_numbers(), card.card_numbers()])
+        .all(|numbers| matches!(numbers, NumberSet::Hashed(_))));
+    assert_eq!(cards[0].score(), 0);
+    assert_eq!(cards[1].score(), 1);
+}
+
+#[test]
+fn day4_part2() {
+    let cards = cards_parser().parse(TEST_INPUT).unwrap();
+
+    assert_eq!(count_cards(&cards), Ok(30));
+}
+
+#[test]
+fn day4_cascade() {
+    let mut cards = cards_parser().parse(TEST_INPUT).unwrap();
+    cards.reverse();
+
+    let report = cascade(&cards).unwrap();
+    assert_eq!(
+        report.iter().map(|card| card.id).collect::<Vec<_>>(),
+        [1, 2, 3, 4, 5, 6]
+    );
+    assert_eq!(
+        report[3],
+        CardCopies {
+            id: 4,
+            matches: 1,
+            copies: 8,
+            won_from: vec![(1, 1), (2, 2), (3, 4)]
+        }
+    );
+    assert_eq!(count_cards(&cards), Ok(30));
+
+    cards[0].id = 2;
+    cards[2].id = 9;
+    let error = check_ids(&cards).unwrap_err();
+    assert_eq!(cascade(&cards), Err(error.clone()));
+    assert_eq!(
+        error.to_string(),
+        "there is more than one card 2 (and 2 more errors)"
+    );
+    let others = error
+        .others()
+        .iter()
+        .map(|e| e.to_string())
+        .collect::<Vec<_>>();
+    assert_eq!(others, ["card 4 is missing", "cards 6 to 8 are missing"]);
+}
+
+#[test]
+fn day4_ids() {
+    let input = "Card 3: 1 | 1
+Card 1: 2 | 3
+Card 2: 4 | 5
+Card 1: 6 | 7
+Card 6: 8 | 9";
+
+    let error = Day4::parse(input).unwrap_err();
+    let errors = std::iter::once(&error)
+        .chain(error.others())
+        .map(|e| {
+            let location = e.location().unwrap();
+            (location.line, location.column, e.message())
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(
+        errors,
+        [
+            (4, 6, "there is more than one card 1"),
+            (5, 6, "cards 4 to 5 are missing"),
+        ]
+    );
+}
+
+#[test]
+fn day4_strict() {
+    let input = "Card 1: 41 48 83 | 83 86  6 31
+Card 2: 13 32 13 | 61 30 68 82
+Card 4: 87 83 | 88 30 70 12
+Card 5:  1 21 53 | 69 82 63 72 16";
+
+    let cards = parse_cards(input, Validation::Lenient).unwrap();
+    assert_eq!(cards[1].winning_numbers().len(), 2);
+
+    let error = parse_cards(input, Validation::Strict).unwrap_err();
+    let errors = std::iter::once(&error)
+        .chain(error.others())
+        .map(|e| {
+            let location = e.location().unwrap();
+            (location.line, location.column, e.message())
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(
+        errors,
+        [
+            (2, 15, "duplicate winning number 13"),
+            (3, 6, "card 4 is out of sequence, expected card 3"),
+            (
+                3,
+                9,
+                "card has 2 winning numbers, expected 3 like most cards"
+            ),
+            (4, 20, "card has 5 card numbers, expected 4 like most cards"),
+        ]
+    );
+    assert!(parse_cards(TEST_INPUT, Validation::Strict).is_ok());
+
+    let error = parse_cards(
+        "Card 1: 1 | 1\nCard 4294967295: 1 | 1\nCard 2: 1 | 1",
+        Validation::Strict,
+    )
+    .unwrap_err();
+    assert_eq!(
+        error.others()[0].to_string(),
+        "line 3, column 6: card 2 is out of sequence, there are no ids after 4294967295"
+    );
+}
@@ -0,0 +1,180 @@
+use std::{collections::HashSet, ops::Range};
+
+// The numbers on one side of a card. Numbers in a range declared up front are
+// kept as bits, a word for every 64 of them, so intersecting two sets over the
+// same range is a handful of instructions. A set with any number outside its
+// range falls back to a `HashSet`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum NumberSet {
+    // Bit `n - start` is set for each number `n`.
+    Bits { start: u32, words: Box<[u64]> },
+    Hashed(HashSet<u32>),
+}
+
+impl NumberSet {
+    // The range sets cover unless told otherwise, which is enough for the
+    // puzzle's cards.
+    pub const DEFAULT_RANGE: Range<u32> = 0..128;
+
+    pub fn new() -> Self {
+        NumberSet::with_range(Self::DEFAULT_RANGE)
+    }
+
+    pub fn with_range(range: Range<u32>) -> Self {
+        let words = range.len().div_ceil(64);
+
+        NumberSet::Bits {
+            start: range.start,
+            words: vec![0; words].into_boxed_slice(),
+        }
+    }
+
+    // The numbers that fit in the bits, which may run a little past the end
+    // of the range the set was made with. `None` once the set is hashed.
+    pub fn range(&self) -> Option<Range<u32>> {
+        match self {
+            NumberSet::Bits { start, words } => {
+                let end = u64::from(*start) + 64 * words.len() as u64;
+                Some(*start..end.min(u64::from(u32::MAX)) as u32)
+            }
+            NumberSet::Hashed(_) => None,
+        }
+    }
+
+    pub fn insert(&mut self, number: u32) {
+        match self {
+            NumberSet::Bits { start, words } => match bit(*start, words, number) {
+                Some((word, mask)) => words[word] |= mask,
+                None => {
+                    let mut hashed = self.iter().collect::<HashSet<_>>();
+                    hashed.insert(number);
+                    *self = NumberSet::Hashed(hashed);
+                }
+            },
+            NumberSet::Hashed(hashed) => {
+                hashed.insert(number);
+            }
+        }
+    }
+
+    pub fn contains(&self, number: u32) -> bool {
+        match self {
+            NumberSet::Bits { start, words } => {
+                bit(*start, words, number).is_some_and(|(word, mask)| words[word] & mask != 0)
+            }
+            NumberSet::Hashed(hashed) => hashed.contains(&number),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        match self {
+            NumberSet::Bits { words, .. } => {
+                words.iter().map(|word| word.count_ones() as usize).sum()
+            }
+            NumberSet::Hashed(hashed) => hashed.len(),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    // The numbers in the set, smallest first if they're kept as bits and in
+    // no particular order otherwise.
+    pub fn iter(&self) -> Box<dyn Iterator<Item = u32> + '_> {
+        match self {
+            &NumberSet::Bits { start, ref words } => {
+                Box::new(words.iter().zip(0u32..).flat_map(move |(&word, i)| {
+                    (0..64)
+                        .filter(move |bit| word & (1 << bit) != 0)
+                        .map(move |bit| start + i * 64 + bit)
+                }))
+            }
+            NumberSet::Hashed(hashed) => Box::new(hashed.iter().copied()),
+        }
+    }
+
+    // How many numbers are in both sets.
+    pub fn intersection_len(&self, other: &NumberSet) -> usize {
+        match (self, other) {
+            (
+                NumberSet::Bits { start, words },
+                NumberSet::Bits {
+                    start: other_start,
+                    words: other_words,
+                },
+            ) if start == other_start => words
+                .iter()
+                .zip(other_words.iter())
+                .map(|(a, b)| (a & b).count_ones() as usize)
+                .sum(),
+            (NumberSet::Hashed(a), NumberSet::Hashed(b)) => a.intersection(b).count(),
+            _ if self.len() <= other.len() => self.iter().filter(|&n| other.contains(n)).count(),
+            _ => other.intersection_len(self),
+        }
+    }
+}
+
+// The word and bit within it a number is kept in, if it's in the bits at all.
+fn bit(start: u32, words: &[u64], number: u32) -> Option<(usize, u64)> {
+    let offset = number.checked_sub(start)? as usize;
+    (offset / 64 < words.len()).then(|| (offset / 64, 1 << (offset % 64)))
+}
+
+impl Default for NumberSet {
+    fn default() -> Self {
+        NumberSet::new()
+    }
+}
+
+impl Extend<u32> for NumberSet {
+    fn extend<I: IntoIterator<Item = u32>>(&mut self, numbers: I) {
+        for number in numbers {
+            self.insert(number);
+        }
+    }
+}
+
+impl FromIterator<u32> for NumberSet {
+    fn from_iter<I: IntoIterator<Item = u32>>(numbers: I) -> Self {
+        let mut set = NumberSet::new();
+        set.extend(numbers);
+
+        set
+    }
+}
+
+#[test]
+fn number_set() {
+    let small = [41, 48, 83, 86, 17].into_iter().collect::<NumberSet>();
+    assert_eq!(small.range(), Some(0..128));
+    assert_eq!(small.len(), 5);
+    assert_eq!(small.iter().collect::<Vec<_>>(), [17, 41, 48, 83, 86]);
+
+    let large = [83, 86, 6, 31, 17, 9, 48, 200]
+        .into_iter()
+        .collect::<NumberSet>();
+    assert!(matches!(large, NumberSet::Hashed(_)));
+    assert!(large.contains(200) && large.contains(6) && !large.contains(41));
+
+    assert_eq!(small.intersection_len(&large), 4);
+    assert_eq!(large.intersection_len(&small), 4);
+    assert_eq!(large.intersection_len(&large), 8);
+    assert!(NumberSet::new().is_empty());
+
+    // Numbers far from zero stay bits when the range says where they are.
+    let mut winning = NumberSet::with_range(1_000..1_200);
+    winning.extend([1_000, 1_063, 1_064, 1_199]);
+    let mut own = NumberSet::with_range(1_000..1_200);
+    own.extend([1_064, 1_100, 1_199]);
+    assert_eq!(winning.range(), Some(1_000..1_256));
+    assert_eq!(
+        winning.iter().collect::<Vec<_>>(),
+        [1_000, 1_063, 1_064, 1_199]
+    );
+    assert_eq!(winning.intersection_len(&own), 2);
+
+    own.insert(5);
+    assert!(matches!(own, NumberSet::Hashed(_)));
+    assert_eq!(winning.intersection_len(&own), 2);
+}
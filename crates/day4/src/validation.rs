@@ -0,0 +1,163 @@
+use std::ops::Range;
+
+use aoc_core::Error;
+
+// How closely cards are checked while parsing.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Validation {
+    // Numbers repeated on a side of a card count once, cards may have any
+    // amount of numbers, and ids may come in any order. Solving still needs
+    // the ids to be unique and without gaps, which `Day4::parse` checks.
+    #[default]
+    Lenient,
+    // Numbers may not repeat on a side of a card, every card has as many
+    // numbers on each side as most cards do, and the ids count up from 1.
+    Strict,
+}
+
+// A number and where it is in the input, in characters like chumsky counts
+// them.
+pub(crate) type Spanned = (u32, Range<usize>);
+
+// A card as it's written, before anything is checked.
+#[derive(Debug, Clone)]
+pub(crate) struct RawCard {
+    pub id: Spanned,
+    pub winning_numbers: Vec<Spanned>,
+    pub card_numbers: Vec<Spanned>,
+}
+
+impl RawCard {
+    fn sides(&self) -> [(&'static str, &[Spanned]); 2] {
+        [
+            ("winning", &self.winning_numbers),
+            ("card", &self.card_numbers),
+        ]
+    }
+}
+
+// An error at a span in characters, as chumsky counts them.
+fn located(input: &str) -> impl Fn(&Range<usize>, String) -> Error + '_ {
+    move |span, message| {
+        let offset = |index: usize| {
+            input
+                .char_indices()
+                .nth(index)
+                .map_or(input.len(), |(offset, _)| offset)
+        };
+        Error::at(input, offset(span.start)..offset(span.end), message)
+    }
+}
+
+// Like `check_ids`, but pointing at the cards: the second of two with the same
+// id, or the card right after missing ones.
+pub(crate) fn check_raw_ids(input: &str, cards: &[RawCard]) -> Result<(), Error> {
+    let ids = cards.iter().map(|card| (card.id.0, &card.id.1));
+
+    match Error::combine(id_errors(ids, located(input))) {
+        Some(e) => Err(e),
+        None => Ok(()),
+    }
+}
+
+// The duplicate and missing ids among `ids`, each with something to say where
+// the id is. Ids are compared in sorted order, and otherwise in the order
+// they're given.
+pub(crate) fn id_errors<T>(
+    ids: impl Iterator<Item = (u32, T)>,
+    error: impl Fn(T, String) -> Error,
+) -> Vec<Error> {
+    let mut ids = ids.collect::<Vec<_>>();
+    ids.sort_by_key(|(id, _)| *id);
+
+    let mut errors = Vec::new();
+    let mut ids = ids.into_iter();
+    let Some((mut previous, _)) = ids.next() else {
+        return errors;
+    };
+    for (id, at) in ids {
+        match id - previous {
+            0 => errors.push(error(at, format!("there is more than one card {id}"))),
+            1 => {}
+            2 => errors.push(error(at, format!("card {} is missing", previous + 1))),
+            _ => errors.push(error(
+                at,
+                format!("cards {} to {} are missing", previous + 1, id - 1),
+            )),
+        }
+        previous = id;
+    }
+
+    errors
+}
+
+pub(crate) fn check_strict(input: &str, cards: &[RawCard]) -> Result<(), Error> {
+    let error = located(input);
+
+    let usual_counts = [0, 1].map(|i| usual_count(cards.iter().map(|c| c.sides()[i].1.len())));
+
+    let mut errors = Vec::new();
+    // `None` after the largest id there can be.
+    let mut expected_id = Some(1);
+    for card in cards {
+        let (id, id_span) = &card.id;
+        match expected_id {
+            Some(expected) if *id != expected => errors.push(error(
+                id_span,
+                format!("card {id} is out of sequence, expected card {expected}"),
+            )),
+            Some(_) => {}
+            None => errors.push(error(
+                id_span,
+                format!(
+                    "card {id} is out of sequence, there are no ids after {}",
+                    u32::MAX
+                ),
+            )),
+        }
+        expected_id = id.checked_add(1);
+
+        for ((name, numbers), usual) in card.sides().into_iter().zip(usual_counts) {
+            for (i, (number, span)) in numbers.iter().enumerate() {
+                if numbers[..i].iter().any(|(other, _)| other == number) {
+                    errors.push(error(span, format!("duplicate {name} number {number}")));
+                }
+            }
+
+            if numbers.len() != usual {
+                let span = match (numbers.first(), numbers.last()) {
+                    (Some((_, first)), Some((_, last))) => first.start..last.end,
+                    _ => id_span.clone(),
+                };
+                errors.push(error(
+                    &span,
+                    format!(
+                        "card has {} {name} numbers, expected {usual} like most cards",
+                        numbers.len()
+                    ),
+                ));
+            }
+        }
+    }
+
+    match Error::combine(errors) {
+        Some(e) => Err(e),
+        None => Ok(()),
+    }
+}
+
+// The most common count, or the first one seen of the most common.
+fn usual_count(counts: impl Iterator<Item = usize>) -> usize {
+    let mut seen: Vec<(usize, usize)> = Vec::new();
+    for count in counts {
+        match seen.iter_mut().find(|(c, _)| *c == count) {
+            Some((_, times)) => *times += 1,
+            None => seen.push((count, 1)),
+        }
+    }
+
+    seen.iter()
+        .rev()
+        .max_by_key(|(_, times)| *times)
+        .map_or(0, |(count, _)| *count)
+}
@@ -0,0 +1,353 @@
+use std::ops::Range;
+
+use aoc_core::{text::lines, Error, Solution};
+use chumsky::prelude::*;
+use rayon::prelude::*;
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    const DAY: u8 = 5;
+
+    type Input = Almanac;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        almanac_parser()
+            .parse(input)
+            .map_err(|e| Error::from_chumsky(input, e))
+    }
+
+    fn part1(almanac: &Self::Input) -> u64 {
+        almanac
+            .seeds
+            .iter()
+            .map(|seed| almanac.resolve_seed_location(*seed))
+            .min()
+            .unwrap()
+    }
+
+    fn part2(almanac: &Self::Input) -> u64 {
+        almanac
+            .seeds_from_ranges()
+            .collect::<Vec<_>>()
+            .into_par_iter()
+            .map(|s| almanac.resolve_seed_location(s))
+            .min()
+            .unwrap()
+    }
+}
+
+pub fn almanac_parser() -> impl Parser<char, Almanac, Error = Simple<char>> {
+    let number = text::int(10).try_map(|n: String, span| {
+        n.parse::<u64>()
+            .map_err(|e| Simple::custom(span, format!("invalid number `{n}`: {e}")))
+    });
+    // The seeds are also pairs of a start and a length for part 2, so check
+    // they make up whole ranges here.
+    let seeds = number
+        .map_with_span(|n, span| (n, span))
+        .separated_by(just(' '))
+        .validate(|seeds: Vec<(u64, Range<usize>)>, _, emit| {
+            if let [.., (n, span)] = &seeds[..] {
+                if seeds.len() % 2 == 1 {
+                    emit(Simple::custom(
+                        span.clone(),
+                        format!("seed {n} has no range length, expected the seeds in pairs"),
+                    ));
+                }
+            }
+            let ranges = seeds
+                .chunks_exact(2)
+                .filter_map(|pair| {
+                    let ((start, start_span), (length, length_span)) = (&pair[0], &pair[1]);
+                    match start.checked_add(*length) {
+                        Some(end) => Some(*start..end),
+                        None => {
+                            emit(Simple::custom(
+                                start_span.start..length_span.end,
+                                "seed range is too large",
+                            ));
+                            None
+                        }
+                    }
+                })
+                .collect();
+
+            (seeds.into_iter().map(|(n, _)| n).collect(), ranges)
+        })
+        .labelled("seeds");
+
+    let mapping = number
+        .then_ignore(just(' '))
+        .then(number)
+        .then_ignore(just(' '))
+        .then(number)
+        .try_map(
+            |((destination_range_start, source_range_start), source_range_length), span| {
+                let source_range_end = source_range_start
+                    .checked_add(source_range_length)
+                    .ok_or_else(|| Simple::custom(span, "source range is too large"))?;
+
+                Ok(Mapping {
+                    destination_range_start,
+                    source_range: source_range_start..source_range_end,
+                })
+            },
+        )
+        .labelled("mapping")
+        .boxed();
+    let map = lines(mapping).map(|mappings| Map { mappings });
+
+    let newlines = text::newline().repeated().at_least(1);
+
+    just("seeds:")
+        .padded()
+        .ignore_then(seeds)
+        .then_ignore(newlines)
+        .then_ignore(just("seed-to-soil map:"))
+        .then_ignore(text::newline())
+        .then(map.clone())
+        .then_ignore(newlines)
+        .then_ignore(just("soil-to-fertilizer map:"))
+        .then_ignore(text::newline())
+        .then(map.clone())
+        .then_ignore(newlines)
+        .then_ignore(just("fertilizer-to-water map:"))
+        .then_ignore(text::newline())
+        .then(map.clone())
+        .then_ignore(newlines)
+        .then_ignore(just("water-to-light map:"))
+        .then_ignore(text::newline())
+        .then(map.clone())
+        .then_ignore(newlines)
+        .then_ignore(just("light-to-temperature map:"))
+        .then_ignore(text::newline())
+        .then(map.clone())
+        .then_ignore(newlines)
+        .then_ignore(just("temperature-to-humidity map:"))
+        .then_ignore(text::newline())
+        .then(map.clone())
+        .then_ignore(newlines)
+        .then_ignore(just("humidity-to-location map:"))
+        .then_ignore(text::newline())
+        .then(map)
+        .then_ignore(text::newline().repeated())
+        .map(
+            |(
+                (
+                    (
+                        (
+                            (
+                                (((seeds, seed_ranges), seed_to_soil_map), soil_to_fertilizer_map),
+                                fertilizer_to_water_map,
+                            ),
+                            water_to_light_map,
+                        ),
+                        light_to_temperature_map,
+                    ),
+                    temperature_to_humidity_map,
+                ),
+                humidity_to_location_map,
+            )| Almanac {
+                seeds,
+                seed_ranges,
+                seed_to_soil_map,
+                soil_to_fertilizer_map,
+                fertilizer_to_water_map,
+                water_to_light_map,
+                light_to_temperature_map,
+                temperature_to_humidity_map,
+                humidity_to_location_map,
+            },
+        )
+        .then_ignore(end())
+}
+
+#[derive(Debug)]
+pub struct Almanac {
+    seeds: Vec<u64>,
+    seed_ranges: Vec<Range<u64>>,
+    seed_to_soil_map: Map,
+    soil_to_fertilizer_map: Map,
+    fertilizer_to_water_map: Map,
+    water_to_light_map: Map,
+    light_to_temperature_map: Map,
+    temperature_to_humidity_map: Map,
+    humidity_to_location_map: Map,
+}
+
+#[derive(Debug)]
+pub struct Map {
+    mappings: Vec<Mapping>,
+}
+
+#[derive(Debug)]
+pub struct Mapping {
+    destination_range_start: u64,
+    source_range: Range<u64>,
+}
+
+impl Almanac {
+    pub fn resolve_seed_location(&self, seed: u64) -> u64 {
+        let mut value = seed;
+        for map in &[
+            &self.seed_to_soil_map,
+            &self.soil_to_fertilizer_map,
+            &self.fertilizer_to_water_map,
+            &self.water_to_light_map,
+            &self.light_to_temperature_map,
+            &self.temperature_to_humidity_map,
+            &self.humidity_to_location_map,
+        ] {
+            value = map.resolve(value);
+        }
+
+        value
+    }
+
+    pub fn seeds_from_ranges(&self) -> impl Iterator<Item = u64> + '_ {
+        self.seed_ranges.iter().flat_map(Range::clone)
+    }
+}
+
+impl Map {
+    fn resolve(&self, value: u64) -> u64 {
+        for mapping in &self.mappings {
+            if let Some(resolved) = mapping.resolve(value) {
+                return resolved;
+            }
+        }
+
+        value
+    }
+}
+
+impl Mapping {
+    fn resolve(&self, value: u64) -> Option<u64> {
+        if self.source_range.contains(&value) {
+            let offset = value - self.source_range.start();
+            Some(self.destination_range_start + offset)
+        } else {
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+const TEST_INPUT: &str = "seeds: 79 14 55 13
+
+seed-to-soil map:
+50 98 2
+52 50 48
+
+soil-to-fertilizer map:
+0 15 37
+37 52 2
+39 0 15
+
+fertilizer-to-water map:
+49 53 8
+0 11 42
+42 0 7
+57 7 4
+
+water-to-light map:
+88 18 7
+18 25 70
+
+light-to-temperature map:
+45 77 23
+81 45 19
+68 64 13
+
+temperature-to-humidity map:
+0 69 1
+1 0 69
+
+humidity-to-location map:
+60 56 37
+56 93 4";
+
+#[test]
+fn day5_part1() {
+    let almanac = almanac_parser().parse(TEST_INPUT).unwrap();
+
+    assert_eq!(almanac.seeds, vec![79, 14, 55, 13]);
+    assert_eq!(almanac.seed_to_soil_map.resolve(79), 81);
+    assert_eq!(almanac.seed_to_soil_map.resolve(14), 14);
+    assert_eq!(almanac.seed_to_soil_map.resolve(55), 57);
+    assert_eq!(almanac.seed_to_soil_map.resolve(13), 13);
+
+    assert_eq!(almanac.resolve_seed_location(79), 82);
+    assert_eq!(almanac.resolve_seed_location(14), 43);
+    assert_eq!(almanac.resolve_seed_location(55), 86);
+    assert_eq!(almanac.resolve_seed_location(13), 35);
+}
+
+#[test]
+fn day5_part2() {
+    let almanac = almanac_parser().parse(TEST_INPUT).unwrap();
+
+    assert_eq!(almanac.seeds_from_ranges().count(), 27);
+    assert_eq!(
+        almanac
+            .seeds_from_ranges()
+            .map(|s| almanac.resolve_seed_location(s))
+            .min()
+            .unwrap(),
+        46
+    );
+}
+
+#[test]
+fn day5_errors() {
+    let input = TEST_INPUT.replacen("52 50 48", "52 50 x8", 1);
+
+    assert_eq!(
+        Day5::parse(&input).unwrap_err().to_string(),
+        "line 5, column 7: unexpected `x`, expected a digit (while parsing the mapping)"
+    );
+
+    let input = TEST_INPUT.replacen("79 14 55 13", "79 14 55", 1);
+    assert_eq!(
+        Day5::parse(&input).unwrap_err().to_string(),
+        "line 1, column 14: seed 55 has no range length, expected the seeds in pairs (while parsing the seeds)"
+    );
+
+    let input = TEST_INPUT.replacen("79 14", "18446744073709551615 14", 1);
+    assert_eq!(
+        Day5::parse(&input).unwrap_err().to_string(),
+        "line 1, column 8: seed range is too large (while parsing the seeds)"
+    );
+}
+
+#[test]
+fn day5_all_errors() {
+    let input = TEST_INPUT
+        .replacen("52 50 48", "52 50 x8", 1)
+        .replacen("0 15 37", "0 15", 1)
+        .replacen("49 53 8", "49 53 99999999999999999999", 1);
+
+    let error = Day5::parse(&input).unwrap_err();
+    let errors = std::iter::once(&error)
+        .chain(error.others())
+        .map(|e| {
+            let location = e.location().unwrap();
+            (location.line, location.column, e.message())
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(
+        errors,
+        [
+            (5, 7, "unexpected `x`, expected a digit"),
+            (8, 5, "unexpected end of line, expected ` `"),
+            (
+                13,
+                7,
+                "invalid number `99999999999999999999`: number too large to fit in target type"
+            ),
+        ]
+    );
+}
@@ -0,0 +1,179 @@
+use aoc_core::{
+    text::{number, words},
+    Error, Solution,
+};
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    const DAY: u8 = 6;
+
+    type Input = Document;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        Ok(Document {
+            races: parse_part1(input)?,
+            single_race: parse_part2(input)?,
+        })
+    }
+
+    fn part1(document: &Self::Input) -> usize {
+        document.races.iter().map(count_ways_to_win).product()
+    }
+
+    fn part2(document: &Self::Input) -> usize {
+        count_ways_to_win(&document.single_race)
+    }
+}
+
+#[derive(Debug)]
+pub struct Document {
+    races: Vec<Race>,
+    // The same sheet, read as a single race by ignoring the spaces.
+    single_race: Race,
+}
+
+#[derive(Debug)]
+pub struct Race {
+    time: u64,
+    distance: u64,
+}
+
+pub fn count_ways_to_win(race: &Race) -> usize {
+    for button_press_time in 1..(race.time / 2 + 1) {
+        let speed = button_press_time;
+        let remaining_time = race.time - button_press_time;
+        let distance_traveled = speed * remaining_time;
+
+        // The first time we encounter this, we know enough to determine the
+        // total number of ways to win, as it's symmetrical.
+        if distance_traveled > race.distance {
+            return (button_press_time..=(race.time - button_press_time)).count();
+        }
+    }
+
+    0
+}
+
+pub fn parse_part1(input: &str) -> Result<Vec<Race>, Error> {
+    let numbers = |index, label| {
+        let line = labelled_line(input, index, label)?;
+        words(&line[label.len()..])
+            .map(|(offset, word)| {
+                let start = label.len() + offset;
+                number(index, line, start..start + word.len())
+            })
+            .collect::<Result<Vec<u64>, _>>()
+    };
+
+    let times = numbers(0, "Time:")?;
+    let distances = numbers(1, "Distance:")?;
+    if times.len() != distances.len() {
+        let line = labelled_line(input, 1, "Distance:")?;
+        return Err(Error::in_line(
+            1,
+            line,
+            0..line.len(),
+            format!(
+                "expected {} distances, one for each race, found {}",
+                times.len(),
+                distances.len()
+            ),
+        ));
+    }
+    if let Some(line) = input.lines().nth(2) {
+        return Err(Error::in_line(
+            2,
+            line,
+            0..line.len(),
+            "unexpected line after the distances",
+        ));
+    }
+
+    Ok(times
+        .into_iter()
+        .zip(distances)
+        .map(|(time, distance)| Race { time, distance })
+        .collect())
+}
+
+pub fn parse_part2(input: &str) -> Result<Race, Error> {
+    // Ignore the spaces between the digits, as if the numbers were one number.
+    let number = |index, label: &str| {
+        let line = labelled_line(input, index, label)?;
+        let span = label.len()..line.len();
+        let digits = line[span.clone()]
+            .chars()
+            .filter(|c| !c.is_ascii_whitespace())
+            .collect::<String>();
+
+        digits.parse::<u64>().map_err(|e| {
+            Error::in_line(index, line, span, format!("invalid number `{digits}`: {e}"))
+        })
+    };
+
+    Ok(Race {
+        time: number(0, "Time:")?,
+        distance: number(1, "Distance:")?,
+    })
+}
+
+// The line at `index`, which should start with `label`.
+fn labelled_line<'a>(input: &'a str, index: usize, label: &str) -> Result<&'a str, Error> {
+    let Some(line) = input.lines().nth(index) else {
+        return Err(Error::at(
+            input,
+            input.len()..input.len(),
+            format!("expected a line starting with `{label}`"),
+        ));
+    };
+    if !line.starts_with(label) {
+        return Err(Error::in_line(
+            index,
+            line,
+            0..line.len(),
+            format!("expected the line to start with `{label}`"),
+        ));
+    }
+
+    Ok(line)
+}
+
+#[cfg(test)]
+const TEST_INPUT: &str = "Time:      7  15   30
+Distance:  9  40  200";
+
+#[test]
+fn day6_part1() {
+    let races = parse_part1(TEST_INPUT).unwrap();
+
+    assert_eq!(count_ways_to_win(&races[0]), 4);
+    assert_eq!(races.iter().map(count_ways_to_win).product::<usize>(), 288);
+}
+
+#[test]
+fn day6_part2() {
+    let race = parse_part2(TEST_INPUT).unwrap();
+
+    assert_eq!(count_ways_to_win(&race), 71503);
+}
+
+#[test]
+fn day6_errors() {
+    let error = |input| Day6::parse(input).unwrap_err().to_string();
+
+    assert_eq!(
+        error("Time: 7 15"),
+        "line 1, column 11: expected a line starting with `Distance:`"
+    );
+    assert_eq!(
+        error("Time: 7 15\nDist: 9 40"),
+        "line 2, column 1: expected the line to start with `Distance:`"
+    );
+    assert_eq!(
+        error("Time: 7 15\nDistance: 9"),
+        "line 2, column 1: expected 2 distances, one for each race, found 1"
+    );
+}
@@ -0,0 +1,301 @@
+use std::{cmp::Ordering, ops::Range};
+
+use aoc_core::{
+    text::{number, words},
+    Error, Solution,
+};
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    const DAY: u8 = 7;
+
+    type Input = Vec<Entry>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        input
+            .lines()
+            .enumerate()
+            .map(|(index, line)| Entry::try_parse(index, line, false))
+            .collect()
+    }
+
+    fn part1(entries: &Self::Input) -> usize {
+        let mut entries = entries.clone();
+        entries.sort();
+
+        total_winnings(&entries)
+    }
+
+    fn part2(entries: &Self::Input) -> usize {
+        let mut entries = entries.iter().map(Entry::with_jokers).collect::<Vec<_>>();
+        entries.sort();
+
+        total_winnings(&entries)
+    }
+}
+
+pub fn total_winnings(sorted_entries: &[Entry]) -> usize {
+    sorted_entries
+        .iter()
+        .enumerate()
+        .map(|(i, e)| e.bid * (i + 1))
+        .sum()
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Hand([u8; 5]);
+
+#[derive(Debug, PartialEq, PartialOrd, Eq, Ord)]
+pub enum HandType {
+    HighCard,
+    OnePair,
+    TwoPair,
+    ThreeOfAKind,
+    FullHouse,
+    FourOfAKind,
+    FiveOfAKind,
+}
+
+impl Hand {
+    // On failure, returns the span of the offending text in `s` along with an
+    // explanation.
+    fn try_parse(s: &str, jokers: bool) -> Result<Self, (Range<usize>, String)> {
+        let count = s.chars().count();
+        if count != 5 {
+            return Err((0..s.len(), format!("a hand has 5 cards, found {count}")));
+        }
+
+        let mut cards = [0; 5];
+        for (i, (offset, c)) in s.char_indices().enumerate() {
+            match c {
+                'A' => cards[i] = 14,
+                'K' => cards[i] = 13,
+                'Q' => cards[i] = 12,
+                'J' => cards[i] = if jokers { 1 } else { 11 },
+                'T' => cards[i] = 10,
+                n if n.is_ascii_digit() && n != '0' && n != '1' => {
+                    cards[i] = n.to_digit(10).expect("Already checked that `n` is a digit") as u8
+                }
+                _ => {
+                    return Err((
+                        offset..offset + c.len_utf8(),
+                        format!("invalid card `{c}`, expected one of `AKQJT98765432`"),
+                    ))
+                }
+            }
+        }
+
+        Ok(Hand(cards))
+    }
+
+    // Reinterpret all jacks in this hand as jokers.
+    fn with_jokers(&self) -> Self {
+        Hand(self.0.map(|card| if card == 11 { 1 } else { card }))
+    }
+
+    pub fn hand_type(&self) -> HandType {
+        let mut counts = [0; 15];
+        let mut jokers = 0;
+        for card in self.0.iter() {
+            if *card == 1 {
+                jokers += 1;
+                continue;
+            }
+            counts[*card as usize] += 1;
+        }
+
+        // Make sure to sort so we look at highest amounts first, and don't
+        // consume the jokers too early.
+        counts.sort();
+
+        let mut pairs = 0;
+        let mut three = false;
+        let mut four = false;
+        let mut five = false;
+        for count in counts.iter().rev() {
+            if *count + jokers == 5 {
+                five = true;
+                jokers = 0;
+            } else if *count + jokers == 4 {
+                four = true;
+                jokers = 0;
+            } else if *count + jokers == 3 {
+                three = true;
+                jokers = 0;
+            } else if *count + jokers == 2 {
+                pairs += 1;
+                jokers = 0;
+            }
+        }
+
+        if five {
+            HandType::FiveOfAKind
+        } else if four {
+            HandType::FourOfAKind
+        } else if three && pairs == 1 {
+            HandType::FullHouse
+        } else if three {
+            HandType::ThreeOfAKind
+        } else if pairs == 2 {
+            HandType::TwoPair
+        } else if pairs == 1 {
+            HandType::OnePair
+        } else {
+            HandType::HighCard
+        }
+    }
+}
+
+impl Ord for Hand {
+    fn cmp(&self, other: &Self) -> Ordering {
+        let self_hand_type = self.hand_type();
+        let other_hand_type = other.hand_type();
+
+        if self.hand_type() != other.hand_type() {
+            return self_hand_type.cmp(&other_hand_type);
+        }
+
+        self.0.cmp(&other.0)
+    }
+}
+
+impl PartialOrd for Hand {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Entry {
+    hand: Hand,
+    bid: usize,
+}
+
+impl Ord for Entry {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.hand.cmp(&other.hand)
+    }
+}
+
+impl PartialOrd for Entry {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Entry {
+    // Parse the line at `index` (counting from 0) in the input.
+    fn try_parse(index: usize, line: &str, jokers: bool) -> Result<Self, Error> {
+        let error = |span, message| Error::in_line(index, line, span, message);
+
+        let mut parts = words(line);
+        let Some((hand_offset, hand)) = parts.next() else {
+            return Err(error(
+                0..line.len(),
+                "expected a hand and a bid".to_string(),
+            ));
+        };
+        let hand = Hand::try_parse(hand, jokers).map_err(|(span, message)| {
+            error(hand_offset + span.start..hand_offset + span.end, message)
+        })?;
+
+        let Some((bid_offset, bid)) = parts.next() else {
+            return Err(error(
+                line.len()..line.len(),
+                "expected a bid after the hand".to_string(),
+            ));
+        };
+        let bid = number(index, line, bid_offset..bid_offset + bid.len())?;
+
+        if let Some((offset, _)) = parts.next() {
+            return Err(error(
+                offset..line.len(),
+                "unexpected text after the bid".to_string(),
+            ));
+        }
+
+        Ok(Entry { hand, bid })
+    }
+
+    pub fn with_jokers(&self) -> Self {
+        Entry {
+            hand: self.hand.with_jokers(),
+            bid: self.bid,
+        }
+    }
+}
+
+#[cfg(test)]
+const TEST_INPUT: &str = "32T3K 765
+T55J5 684
+KK677 28
+KTJJT 220
+QQQJA 483";
+
+#[test]
+fn day7_part1() {
+    let mut entries = TEST_INPUT
+        .lines()
+        .enumerate()
+        .map(|(index, line)| Entry::try_parse(index, line, false))
+        .collect::<Result<Vec<_>, _>>()
+        .unwrap();
+
+    assert_eq!(entries.len(), 5);
+    assert_eq!(entries[0].hand, Hand([3, 2, 10, 3, 13]));
+    assert_eq!(entries[0].bid, 765);
+    assert_eq!(entries[0].hand.hand_type(), HandType::OnePair);
+
+    entries.sort();
+
+    assert_eq!(
+        entries
+            .iter()
+            .enumerate()
+            .map(|(i, e)| e.bid * (i + 1))
+            .sum::<usize>(),
+        6440
+    );
+}
+
+#[test]
+fn day7_part2() {
+    let mut entries = TEST_INPUT
+        .lines()
+        .enumerate()
+        .map(|(index, line)| Entry::try_parse(index, line, true))
+        .collect::<Result<Vec<_>, _>>()
+        .unwrap();
+
+    assert_eq!(entries[3].hand.hand_type(), HandType::FourOfAKind);
+    assert_eq!(entries[1].hand, Hand([10, 5, 5, 1, 5]));
+    assert_eq!(entries[1].hand.hand_type(), HandType::FourOfAKind);
+
+    entries.sort();
+
+    assert_eq!(
+        entries
+            .iter()
+            .enumerate()
+            .map(|(i, e)| e.bid * (i + 1))
+            .sum::<usize>(),
+        5905
+    );
+}
+
+#[test]
+fn day7_errors() {
+    let error = |input| Day7::parse(input).unwrap_err().to_string();
+
+    assert_eq!(
+        error("32T3K 765\nT55J5J 684"),
+        "line 2, column 1: a hand has 5 cards, found 6"
+    );
+    assert_eq!(
+        error("32T3X 765"),
+        "line 1, column 5: invalid card `X`, expected one of `AKQJT98765432`"
+    );
+}
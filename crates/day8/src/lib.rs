@@ -0,0 +1,289 @@
+use aoc_core::{text::lines, Error, Solution};
+use chumsky::prelude::*;
+use std::collections::{HashMap, HashSet};
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    const DAY: u8 = 8;
+
+    type Input = Network;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        Network::parser()
+            .parse(input)
+            .map_err(|e| Error::from_chumsky(input, e))
+    }
+
+    fn part1(network: &Self::Input) -> usize {
+        count_steps(network)
+    }
+
+    fn part2(network: &Self::Input) -> usize {
+        count_steps_parallel(network)
+    }
+}
+
+pub fn count_steps(network: &Network) -> usize {
+    let mut current_node = network
+        .nodes
+        .get("AAA")
+        .expect("All nodes must be reachable");
+
+    network
+        .directions
+        .iter().cycle()
+        .take_while(|direction| {
+            let destination = match direction {
+                Direction::Left => &current_node.left,
+                Direction::Right => &current_node.right,
+            };
+            current_node = network
+                .nodes
+                .get(destination)
+                .expect("All nodes must be reachable");
+
+            destination != "ZZZ"
+        })
+        .count()
+        // Add 1 to account for the final step to ZZZ
+        + 1
+}
+
+pub fn count_steps_parallel(network: &Network) -> usize {
+    // Find the path lenghts for each node starting with A
+    let denominators = network
+        .nodes
+        .iter()
+        .filter(|(name, _)| name.ends_with('A'))
+        .map(|(_, node)| {
+            let mut current_node = node;
+
+            network
+                .directions
+                .iter()
+                .cycle()
+                .take_while(|direction| {
+                    let destination = match direction {
+                        Direction::Left => &current_node.left,
+                        Direction::Right => &current_node.right,
+                    };
+                    current_node = network
+                        .nodes
+                        .get(destination)
+                        .expect("All nodes must be reachable");
+
+                    !destination.ends_with('Z')
+                })
+                .count()
+                + 1
+        })
+        .collect::<Vec<_>>();
+
+    // Find the lowest common multiple of the path lengths
+    lcm(&denominators)
+}
+
+fn lcm(input: &[usize]) -> usize {
+    let mut lcm = input[0];
+    for n in input {
+        lcm = lcm * n / gcd(lcm, *n);
+    }
+    lcm
+}
+
+fn gcd(mut a: usize, mut b: usize) -> usize {
+    while b != 0 {
+        let t = b;
+        b = a % b;
+        a = t;
+    }
+    a
+}
+
+#[derive(Debug, Clone)]
+pub enum Direction {
+    Left,
+    Right,
+}
+
+#[derive(Debug)]
+pub struct Node {
+    left: String,
+    right: String,
+}
+
+#[derive(Debug)]
+pub struct Network {
+    directions: Vec<Direction>,
+    nodes: HashMap<String, Node>,
+}
+
+impl Network {
+    pub fn parser() -> impl Parser<char, Self, Error = Simple<char>> {
+        // Accept any character as a direction so that all the invalid ones are
+        // reported, not only the first.
+        let direction = none_of("\r\n")
+            .validate(|c, span, emit| match c {
+                'L' => Direction::Left,
+                'R' => Direction::Right,
+                c => {
+                    emit(Simple::expected_input_found(
+                        span,
+                        [Some('L'), Some('R')],
+                        Some(c),
+                    ));
+                    Direction::Left
+                }
+            })
+            .labelled("direction");
+        let node_name =
+            filter::<_, _, Simple<char>>(|c: &char| c.is_ascii_alphanumeric() || *c == '_')
+                .repeated()
+                .exactly(3)
+                .collect::<String>();
+
+        // Keep track of where each node is referenced, so references to nodes
+        // that don't exist can be reported.
+        let reference = node_name.map_with_span(|name, span| (name, span));
+        let node = reference
+            .labelled("source")
+            .then_ignore(just(',').padded_by(just(' ').repeated()))
+            .then(reference)
+            .labelled("destination")
+            .delimited_by(just('('), just(')'))
+            .boxed();
+
+        direction
+            .repeated()
+            .at_least(1)
+            .then_ignore(text::newline().repeated().exactly(2))
+            .then(lines(
+                node_name.labelled("node name").then(
+                    // Recover after the name, so the node still counts as
+                    // defined when checking the references to it.
+                    just('=')
+                        .padded_by(just(' ').repeated())
+                        .ignore_then(node)
+                        .then_ignore(text::newline().or(end()).rewind())
+                        .map(Some)
+                        .recover_with(skip_until(['\n'], |_| None)),
+                ),
+            ))
+            .then_ignore(text::newline().repeated())
+            .then_ignore(end())
+            .validate(|(directions, nodes): (_, Vec<_>), _, emit| {
+                let names = nodes.iter().map(|(name, _)| name).collect::<HashSet<_>>();
+                for ((left, left_span), (right, right_span)) in
+                    nodes.iter().flat_map(|(_, node)| node)
+                {
+                    for (name, span) in [(left, left_span), (right, right_span)] {
+                        if !names.contains(name) {
+                            emit(Simple::custom(
+                                span.clone(),
+                                format!("node `{name}` is not defined"),
+                            ));
+                        }
+                    }
+                }
+
+                let nodes = nodes
+                    .into_iter()
+                    .filter_map(|(name, node)| Some((name, node?)))
+                    .map(|(name, ((left, _), (right, _)))| (name, Node { left, right }))
+                    .collect();
+
+                Network { directions, nodes }
+            })
+    }
+}
+
+#[cfg(test)]
+const TEST_INPUT_1: &str = "RL
+
+AAA = (BBB, CCC)
+BBB = (DDD, EEE)
+CCC = (ZZZ, GGG)
+DDD = (DDD, DDD)
+EEE = (EEE, EEE)
+GGG = (GGG, GGG)
+ZZZ = (ZZZ, ZZZ)";
+
+#[cfg(test)]
+const TEST_INPUT_2: &str = "LLR
+
+AAA = (BBB, BBB)
+BBB = (AAA, ZZZ)
+ZZZ = (ZZZ, ZZZ)";
+
+#[test]
+fn day8_part1() {
+    let network = Network::parser().parse(TEST_INPUT_1).unwrap();
+    assert_eq!(count_steps(&network), 2);
+
+    let network = Network::parser().parse(TEST_INPUT_2).unwrap();
+    assert_eq!(count_steps(&network), 6);
+}
+
+#[cfg(test)]
+const TEST_INPUT_3: &str = "LR
+
+11A = (11B, XXX)
+11B = (XXX, 11Z)
+11Z = (11B, XXX)
+22A = (22B, XXX)
+22B = (22C, 22C)
+22C = (22Z, 22Z)
+22Z = (22B, 22B)
+XXX = (XXX, XXX)";
+
+#[test]
+fn day8_part2() {
+    let network = Network::parser().parse(TEST_INPUT_3).unwrap();
+    assert_eq!(count_steps_parallel(&network), 6);
+}
+
+#[test]
+fn day8_errors() {
+    let input = "LRX\n\nAAA = (BBB, BBB)\nBBB = (AAA, ZZZ)\nZZZ = (ZZZ, ZZZ)\n";
+
+    assert_eq!(
+        Day8::parse(input).unwrap_err().to_string(),
+        "line 1, column 3: unexpected `X`, expected one of `L`, `R` (while parsing the direction)"
+    );
+}
+
+#[test]
+fn day8_all_errors() {
+    let errors = |input| {
+        let error = Day8::parse(input).unwrap_err();
+        std::iter::once(&error)
+            .chain(error.others())
+            .map(|e| {
+                let location = e.location().unwrap();
+                (location.line, location.column, e.message().to_string())
+            })
+            .collect::<Vec<_>>()
+    };
+
+    assert_eq!(
+        errors("LXR?\n\nAAA = (BBB, QQQ)\nBBB = (AAA, ZZZ)\nZZZ = (ZZZ, YYY)"),
+        [
+            (1, 2, "unexpected `X`, expected one of `L`, `R`".to_string()),
+            (1, 4, "unexpected `?`, expected one of `L`, `R`".to_string()),
+            (3, 13, "node `QQQ` is not defined".to_string()),
+            (5, 13, "node `YYY` is not defined".to_string()),
+        ]
+    );
+    // `BBB` is still defined after its line fails to parse, so only the
+    // reference to `CCC` is reported.
+    assert_eq!(
+        errors("LR\n\nAAA = (BBB, BBB)\nBBB = AAA, ZZZ\nZZZ = (ZZZ, CCC)"),
+        [
+            (4, 7, "unexpected `A`, expected one of ` `, `(`".to_string()),
+            (5, 13, "node `CCC` is not defined".to_string()),
+        ]
+    );
+}
@@ -0,0 +1,116 @@
+use aoc_core::{
+    text::{number, words},
+    Error, Solution,
+};
+
+pub struct Day9;
+
+impl Solution for Day9 {
+    const DAY: u8 = 9;
+
+    type Input = Vec<Vec<i64>>;
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> i64 {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> i64 {
+        part2(input)
+    }
+}
+
+pub fn parse(input: &str) -> Result<Vec<Vec<i64>>, Error> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(index, line)| {
+            if line.trim().is_empty() {
+                return Err(Error::in_line(
+                    index,
+                    line,
+                    0..line.len(),
+                    "expected a history of at least one value",
+                ));
+            }
+
+            words(line)
+                .map(|(offset, word)| number(index, line, offset..offset + word.len()))
+                .collect()
+        })
+        .collect()
+}
+
+pub fn part1(input: &[Vec<i64>]) -> i64 {
+    input
+        .iter()
+        .map(|row| predict_number(row, PredictionMode::Next))
+        .sum()
+}
+
+pub fn part2(input: &[Vec<i64>]) -> i64 {
+    input
+        .iter()
+        .map(|row| predict_number(row, PredictionMode::Previous))
+        .sum()
+}
+
+enum PredictionMode {
+    Next,
+    Previous,
+}
+
+fn predict_number(row: &[i64], mode: PredictionMode) -> i64 {
+    let differences = row
+        .windows(2)
+        .map(|pair| pair[1] - pair[0])
+        .collect::<Vec<_>>();
+
+    if differences.iter().all(|n| *n == 0) {
+        row[match mode {
+            PredictionMode::Next => row.len() - 1,
+            PredictionMode::Previous => 0,
+        }]
+    } else {
+        match mode {
+            PredictionMode::Next => row[row.len() - 1] + predict_number(&differences, mode),
+            PredictionMode::Previous => row[0] - predict_number(&differences, mode),
+        }
+    }
+}
+
+#[cfg(test)]
+const TEST_INPUT: &str = "0 3 6 9 12 15
+1 3 6 10 15 21
+10 13 16 21 30 45";
+
+#[test]
+fn day9_part1() {
+    let input = parse(TEST_INPUT).unwrap();
+    assert_eq!(part1(&input), 114);
+}
+
+#[test]
+fn day9_part2() {
+    let input = parse(TEST_INPUT).unwrap();
+    assert_eq!(part2(&input), 2);
+}
+
+#[test]
+fn day9_errors() {
+    let error = |input| Day9::parse(input).unwrap_err().to_string();
+
+    assert_eq!(
+        error("0 3 6\n\n1 3 x"),
+        "line 2, column 1: expected a history of at least one value"
+    );
+    assert_eq!(
+        error("0 3 6\n1 3 x"),
+        "line 2, column 5: invalid number `x`: invalid digit found in string"
+    );
+}